@@ -0,0 +1,173 @@
+use crate::math::big_fraction::BigFraction;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Binary floating point number `mantissa·2^exponent` rounded to `precision` bits with an unbounded exponent,
+/// operations on two numbers keep the larger precision
+#[derive(Debug, Clone)]
+pub struct BigFloat {
+    mantissa: BigInt,
+    exponent: i64,
+    precision: u32,
+}
+
+impl BigFloat {
+    pub fn zero(precision: u32) -> BigFloat {
+        BigFloat { mantissa: BigInt::zero(), exponent: 0, precision }
+    }
+
+    pub fn from_int(value: &BigInt, precision: u32) -> BigFloat {
+        BigFloat::normalized(value.clone(), 0, precision)
+    }
+
+    pub fn from_fraction(value: &BigFraction, precision: u32) -> BigFloat {
+        let denominator: BigInt = value.get_denominator();
+        let shift: u64 = precision as u64 + denominator.bits() + 1;
+        let quotient: BigInt = (value.get_numerator() << shift as usize) / denominator;
+        BigFloat::normalized(quotient, -(shift as i64), precision)
+    }
+
+    fn normalized(mantissa: BigInt, exponent: i64, precision: u32) -> BigFloat {
+        if mantissa.is_zero() {
+            return BigFloat::zero(precision);
+        }
+        let bits: u64 = mantissa.bits();
+        if bits <= precision as u64 {
+            return BigFloat { mantissa, exponent, precision };
+        }
+        let shift: u64 = bits - precision as u64;
+        let (mut quotient, remainder) = mantissa.div_mod_floor(&(BigInt::one() << shift as usize));
+        if remainder << 1usize >= BigInt::one() << shift as usize {
+            quotient += 1;
+        }
+        BigFloat::normalized(quotient, exponent + shift as i64, precision)
+    }
+
+    pub fn get_precision(&self) -> u32 {
+        self.precision
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    /// Position of the highest bit, `|self| < 2^top`
+    fn top(&self) -> i64 {
+        self.exponent + self.mantissa.bits() as i64
+    }
+
+    pub fn abs(&self) -> BigFloat {
+        BigFloat { mantissa: self.mantissa.abs(), exponent: self.exponent, precision: self.precision }
+    }
+
+    /// The nearest integer, halves are rounded up
+    pub fn round(&self) -> BigInt {
+        if self.exponent >= 0 {
+            return &self.mantissa << self.exponent as usize;
+        }
+        if self.top() < 0 {
+            return BigInt::zero();
+        }
+        let shift: usize = -self.exponent as usize;
+        (&self.mantissa + (BigInt::one() << (shift - 1))).div_floor(&(BigInt::one() << shift))
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let excess: u64 = self.mantissa.bits().saturating_sub(64);
+        let mantissa: f64 = (&self.mantissa >> excess as usize).to_f64().expect("At most 64 bits");
+        let exponent: i64 = self.exponent + excess as i64;
+        mantissa * 2f64.powi(exponent.max(i32::MIN as i64).min(i32::MAX as i64) as i32)
+    }
+
+    fn with_precision(&self, precision: u32) -> BigFloat {
+        BigFloat::normalized(self.mantissa.clone(), self.exponent, precision)
+    }
+}
+
+impl PartialEq for BigFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let signs: Ordering = self.mantissa.signum().cmp(&other.mantissa.signum());
+        if signs != Ordering::Equal || self.is_zero() {
+            return Some(signs);
+        }
+        let magnitude: Ordering = match self.top().cmp(&other.top()) {
+            Ordering::Equal => {
+                let exponent: i64 = self.exponent.min(other.exponent);
+                let a: BigInt = self.mantissa.abs() << (self.exponent - exponent) as usize;
+                let b: BigInt = other.mantissa.abs() << (other.exponent - exponent) as usize;
+                a.cmp(&b)
+            }
+            ordering => ordering,
+        };
+        Some(if self.mantissa.is_negative() { magnitude.reverse() } else { magnitude })
+    }
+}
+
+impl Add for BigFloat {
+    type Output = BigFloat;
+
+    fn add(self, other: BigFloat) -> BigFloat {
+        let precision: u32 = self.precision.max(other.precision);
+        if other.is_zero() {
+            return self.with_precision(precision);
+        }
+        if self.is_zero() {
+            return other.with_precision(precision);
+        }
+        let (large, small) = if self.top() >= other.top() { (self, other) } else { (other, self) };
+        // the smaller operand lies entirely below the rounding position of the sum
+        if small.top() + (precision as i64) + 2 < large.top() {
+            return large.with_precision(precision);
+        }
+        let exponent: i64 = large.exponent.min(small.exponent);
+        let sum: BigInt = (large.mantissa << (large.exponent - exponent) as usize) + (small.mantissa << (small.exponent - exponent) as usize);
+        BigFloat::normalized(sum, exponent, precision)
+    }
+}
+
+impl Sub for BigFloat {
+    type Output = BigFloat;
+
+    fn sub(self, other: BigFloat) -> BigFloat {
+        self + -other
+    }
+}
+
+impl Mul for BigFloat {
+    type Output = BigFloat;
+
+    fn mul(self, other: BigFloat) -> BigFloat {
+        BigFloat::normalized(self.mantissa * other.mantissa, self.exponent + other.exponent, self.precision.max(other.precision))
+    }
+}
+
+impl Div for BigFloat {
+    type Output = BigFloat;
+
+    fn div(self, other: BigFloat) -> BigFloat {
+        if other.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        let precision: u32 = self.precision.max(other.precision);
+        let shift: u64 = precision as u64 + other.mantissa.bits() + 1;
+        let quotient: BigInt = (self.mantissa << shift as usize) / other.mantissa;
+        BigFloat::normalized(quotient, self.exponent - other.exponent - shift as i64, precision)
+    }
+}
+
+impl Neg for BigFloat {
+    type Output = BigFloat;
+
+    fn neg(self) -> BigFloat {
+        BigFloat { mantissa: -self.mantissa, exponent: self.exponent, precision: self.precision }
+    }
+}
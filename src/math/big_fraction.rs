@@ -95,6 +95,86 @@ impl hash::Hash for BigFraction {
     }
 }
 
+impl From<BigInt> for BigFraction {
+    fn from(value: BigInt) -> Self {
+        BigFraction { ntor: value, dtor: BigInt::one() }
+    }
+}
+
+impl From<i64> for BigFraction {
+    fn from(value: i64) -> Self {
+        BigFraction::from(BigInt::from(value))
+    }
+}
+
+impl Ord for BigFraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.ntor * &other.dtor).cmp(&(&other.ntor * &self.dtor))
+    }
+}
+
+impl PartialOrd for BigFraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BigFraction {
+    type Output = BigFraction;
+
+    fn add(self, other: BigFraction) -> BigFraction {
+        BigFraction::add(self, other)
+    }
+}
+
+impl Sub for BigFraction {
+    type Output = BigFraction;
+
+    fn sub(self, other: BigFraction) -> BigFraction {
+        BigFraction::sub(self, other)
+    }
+}
+
+impl Mul for BigFraction {
+    type Output = BigFraction;
+
+    fn mul(self, other: BigFraction) -> BigFraction {
+        BigFraction::mul(self, other)
+    }
+}
+
+impl Div for BigFraction {
+    type Output = BigFraction;
+
+    fn div(self, other: BigFraction) -> BigFraction {
+        BigFraction::div(self, other).expect("attempt to divide by zero")
+    }
+}
+
+impl Neg for BigFraction {
+    type Output = BigFraction;
+
+    fn neg(self) -> BigFraction {
+        self.negate()
+    }
+}
+
+impl Zero for BigFraction {
+    fn zero() -> Self {
+        BigFraction::get_zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.ntor.is_zero()
+    }
+}
+
+impl One for BigFraction {
+    fn one() -> Self {
+        BigFraction::get_one()
+    }
+}
+
 const FRACTIONAL_BITS: u32 = 80;
 const SIZE_FRACTIONAL: u128 = 1u128<<FRACTIONAL_BITS;
 
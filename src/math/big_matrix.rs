@@ -0,0 +1,463 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_vector::{BigVector, VectorFormat};
+use crate::math::decomposition::bareiss;
+use crate::math::decomposition::hermite_normal_form::{HermiteNormalForm, HnfConvention};
+use crate::math::decomposition::ldl_decomposition::LDLDecomposition;
+use crate::math::decomposition::lu_decomposition::{LUDecomposition, SolveError, SolveErrorKind};
+use crate::math::decomposition::smith_normal_form::SmithNormalForm;
+use core::fmt;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BigMatrix {
+    numbers: Vec<BigFraction>,
+    row_count: usize,
+    col_count: usize,
+}
+
+impl BigMatrix {
+    pub fn zeros(row_count: usize, col_count: usize) -> BigMatrix {
+        BigMatrix {
+            numbers: vec![BigFraction::get_zero(); row_count * col_count],
+            row_count,
+            col_count,
+        }
+    }
+
+    pub fn identity(size: usize) -> BigMatrix {
+        let mut matrix: BigMatrix = BigMatrix::zeros(size, size);
+        for i in 0..size {
+            matrix.numbers[i * size + i] = BigFraction::get_one();
+        }
+        matrix
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> BigFraction>(row_count: usize, col_count: usize, mut f: F) -> BigMatrix {
+        let mut numbers: Vec<BigFraction> = Vec::with_capacity(row_count * col_count);
+        for row in 0..row_count {
+            for col in 0..col_count {
+                numbers.push(f(row, col));
+            }
+        }
+        BigMatrix { numbers, row_count, col_count }
+    }
+
+    pub fn from_rows(rows: &[BigVector]) -> Result<BigMatrix, String> {
+        let col_count: usize = rows.first().map_or(0, |row| row.dimension());
+        let mut numbers: Vec<BigFraction> = Vec::with_capacity(rows.len() * col_count);
+        for (i, row) in rows.iter().enumerate() {
+            if row.dimension() != col_count {
+                return Err(format!("Row {} has dimension {} instead of {}", i, row.dimension(), col_count));
+            }
+            numbers.extend(row.iter().cloned());
+        }
+        Ok(BigMatrix { numbers, row_count: rows.len(), col_count })
+    }
+
+    pub fn from_columns(columns: &[BigVector]) -> Result<BigMatrix, String> {
+        Ok(BigMatrix::from_rows(columns)?.transpose())
+    }
+
+    pub(crate) fn from_row_vecs(rows: Vec<Vec<BigFraction>>, col_count: usize) -> BigMatrix {
+        let row_count: usize = rows.len();
+        let numbers: Vec<BigFraction> = rows.into_iter().flatten().collect();
+        debug_assert_eq!(numbers.len(), row_count * col_count);
+        BigMatrix { numbers, row_count, col_count }
+    }
+
+    pub(crate) fn to_row_vecs(&self) -> Vec<Vec<BigFraction>> {
+        (0..self.row_count).map(|row| self.numbers[row * self.col_count..(row + 1) * self.col_count].to_vec()).collect()
+    }
+
+    /// The column count is explicit so that a matrix without rows keeps its shape
+    pub(crate) fn from_integer_rows(rows: &[Vec<BigInt>], col_count: usize) -> BigMatrix {
+        BigMatrix::from_fn(rows.len(), col_count, |row, col| BigFraction::from(rows[row][col].clone()))
+    }
+
+    /// Numerators of every row, only meaningful for integral matrices
+    pub(crate) fn to_integer_rows(&self) -> Vec<Vec<BigInt>> {
+        (0..self.row_count)
+            .map(|row| self.numbers[row * self.col_count..(row + 1) * self.col_count].iter().map(|x| x.get_numerator()).collect())
+            .collect()
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.row_count == self.col_count
+    }
+
+    pub fn is_integral(&self) -> bool {
+        self.numbers.iter().all(|x| x.get_denominator().is_one())
+    }
+
+    fn check_index(&self, row: usize, col: usize) -> Result<usize, String> {
+        if row >= self.row_count || col >= self.col_count {
+            return Err(format!("Index ({}, {}), dimensions {}x{}", row, col, self.row_count, self.col_count));
+        }
+        Ok(row * self.col_count + col)
+    }
+
+    /// The least common multiple `d` of all denominators together with the integral matrix `d·self`
+    pub fn common_denominator(&self) -> (BigInt, BigMatrix) {
+        let denominator: BigInt = self.numbers.iter().fold(BigInt::one(), |lcm, x| lcm.lcm(&x.get_denominator()));
+        let numbers: Vec<BigFraction> = self.numbers.iter().map(|x| x.clone().mul_int(denominator.clone())).collect();
+        (denominator, BigMatrix { numbers, row_count: self.row_count, col_count: self.col_count })
+    }
+
+    /// Clears the denominators of every row separately, row `i` of the result is `d_i` times row `i`
+    pub fn row_denominators(&self) -> (Vec<BigInt>, BigMatrix) {
+        let (denominators, rows): (Vec<BigInt>, Vec<BigVector>) = self.rows().iter().map(|row| row.common_denominator()).unzip();
+        (denominators, BigMatrix::from_row_vecs(rows.iter().map(|row| row.to_vec()).collect(), self.col_count))
+    }
+
+    /// Clears the denominators of every column separately, column `j` of the result is `d_j` times column `j`
+    pub fn column_denominators(&self) -> (Vec<BigInt>, BigMatrix) {
+        let (denominators, transpose) = self.transpose().row_denominators();
+        (denominators, transpose.transpose())
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Result<BigFraction, String> {
+        let index: usize = self.check_index(row, col)?;
+        Ok(self.numbers[index].clone())
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: BigFraction) -> Result<BigFraction, String> {
+        let index: usize = self.check_index(row, col)?;
+        self.numbers[index] = value.clone();
+        Ok(value)
+    }
+
+    /// Borrowed view of a row, rows are stored contiguously
+    pub fn row_view(&self, row: usize) -> Result<&[BigFraction], String> {
+        if row >= self.row_count {
+            return Err(format!("Row {}, row count {}", row, self.row_count));
+        }
+        Ok(&self.numbers[row * self.col_count..(row + 1) * self.col_count])
+    }
+
+    pub fn get_row(&self, row: usize) -> Result<BigVector, String> {
+        Ok(BigVector::new(self.row_view(row)?))
+    }
+
+    pub fn get_column(&self, col: usize) -> Result<BigVector, String> {
+        if col >= self.col_count {
+            return Err(format!("Column {}, column count {}", col, self.col_count));
+        }
+        Ok(BigVector::from_fn(self.row_count, |row| self.numbers[row * self.col_count + col].clone()))
+    }
+
+    pub fn set_row(&mut self, row: usize, vector: &BigVector) -> Result<(), String> {
+        if row >= self.row_count {
+            return Err(format!("Row {}, row count {}", row, self.row_count));
+        }
+        if vector.dimension() != self.col_count {
+            return Err(format!("The vector ({}) does not match the column count ({})", vector.dimension(), self.col_count));
+        }
+        for (col, value) in vector.iter().enumerate() {
+            self.numbers[row * self.col_count + col] = value.clone();
+        }
+        Ok(())
+    }
+
+    pub fn set_column(&mut self, col: usize, vector: &BigVector) -> Result<(), String> {
+        if col >= self.col_count {
+            return Err(format!("Column {}, column count {}", col, self.col_count));
+        }
+        if vector.dimension() != self.row_count {
+            return Err(format!("The vector ({}) does not match the row count ({})", vector.dimension(), self.row_count));
+        }
+        for (row, value) in vector.iter().enumerate() {
+            self.numbers[row * self.col_count + col] = value.clone();
+        }
+        Ok(())
+    }
+
+    pub fn rows(&self) -> Vec<BigVector> {
+        (0..self.row_count).map(|row| BigVector::new(&self.numbers[row * self.col_count..(row + 1) * self.col_count])).collect()
+    }
+
+    pub fn columns(&self) -> Vec<BigVector> {
+        self.transpose().rows()
+    }
+
+    pub fn multiply(&self, other: &BigMatrix) -> Result<BigMatrix, String> {
+        if self.col_count != other.row_count {
+            return Err(format!("Can not multiply {}x{} by {}x{}", self.row_count, self.col_count, other.row_count, other.col_count));
+        }
+        let mut result: BigMatrix = BigMatrix::zeros(self.row_count, other.col_count);
+        for row in 0..self.row_count {
+            for k in 0..self.col_count {
+                let value: &BigFraction = &self.numbers[row * self.col_count + k];
+                if value.is_zero() {
+                    continue;
+                }
+                for col in 0..other.col_count {
+                    let index: usize = row * other.col_count + col;
+                    let product: BigFraction = value.clone().mul(other.numbers[k * other.col_count + col].clone());
+                    result.numbers[index] = result.numbers[index].clone().add(product);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn multiply_vector(&self, vector: &BigVector) -> Result<BigVector, String> {
+        if self.col_count != vector.dimension() {
+            return Err(format!("Can not multiply {}x{} by a vector of dimension {}", self.row_count, self.col_count, vector.dimension()));
+        }
+        let mut result: Vec<BigFraction> = Vec::with_capacity(self.row_count);
+        for row in 0..self.row_count {
+            let mut value: BigFraction = BigFraction::get_zero();
+            for (a, b) in self.numbers[row * self.col_count..(row + 1) * self.col_count].iter().zip(vector.iter()) {
+                value = value.add(a.clone().mul(b.clone()));
+            }
+            result.push(value);
+        }
+        Ok(BigVector::from(result))
+    }
+
+    pub fn transpose(&self) -> BigMatrix {
+        BigMatrix::from_fn(self.col_count, self.row_count, |row, col| self.numbers[col * self.col_count + row].clone())
+    }
+
+    pub fn submatrix(&self, start_row: usize, start_col: usize, row_count: usize, col_count: usize) -> Result<BigMatrix, String> {
+        let fits = |start: usize, count: usize, size: usize| matches!(start.checked_add(count), Some(end) if end <= size);
+        if !fits(start_row, row_count, self.row_count) || !fits(start_col, col_count, self.col_count) {
+            return Err(format!("Submatrix {}x{} at ({}, {}) does not fit in {}x{}", row_count, col_count, start_row, start_col, self.row_count, self.col_count));
+        }
+        Ok(BigMatrix::from_fn(row_count, col_count, |row, col| self.numbers[(start_row + row) * self.col_count + start_col + col].clone()))
+    }
+
+    pub fn swap_rows(&mut self, i: usize, j: usize) -> Result<(), String> {
+        if i >= self.row_count || j >= self.row_count {
+            return Err(format!("Rows {} and {}, row count {}", i, j, self.row_count));
+        }
+        if i != j {
+            for col in 0..self.col_count {
+                self.numbers.swap(i * self.col_count + col, j * self.col_count + col);
+            }
+        }
+        Ok(())
+    }
+
+    /// `row[target] += scalar * row[source]`
+    pub fn add_row_multiple(&mut self, target: usize, source: usize, scalar: &BigFraction) -> Result<(), String> {
+        if target >= self.row_count || source >= self.row_count {
+            return Err(format!("Rows {} and {}, row count {}", target, source, self.row_count));
+        }
+        if scalar.is_zero() {
+            return Ok(());
+        }
+        for col in 0..self.col_count {
+            let value: BigFraction = self.numbers[source * self.col_count + col].clone().mul(scalar.clone());
+            let index: usize = target * self.col_count + col;
+            self.numbers[index] = self.numbers[index].clone().add(value);
+        }
+        Ok(())
+    }
+
+    pub fn lu_decomposition(&self) -> Result<LUDecomposition, String> {
+        LUDecomposition::decompose(self)
+    }
+
+    /// `A·A^T`, the pairwise inner products of the rows
+    pub fn gram_matrix(&self) -> BigMatrix {
+        let rows: Vec<&[BigFraction]> = (0..self.row_count).map(|row| &self.numbers[row * self.col_count..(row + 1) * self.col_count]).collect();
+        let mut gram: BigMatrix = BigMatrix::zeros(self.row_count, self.row_count);
+        for i in 0..self.row_count {
+            for j in 0..=i {
+                let value: BigFraction = rows[i].iter().zip(rows[j].iter())
+                    .fold(BigFraction::get_zero(), |sum, (a, b)| sum.add(a.clone().mul(b.clone())));
+                gram.numbers[i * self.row_count + j] = value.clone();
+                gram.numbers[j * self.row_count + i] = value;
+            }
+        }
+        gram
+    }
+
+    /// Exact `L·D·L^T` factorization of a symmetric positive definite matrix
+    pub fn ldl_decomposition(&self) -> Result<LDLDecomposition, String> {
+        LDLDecomposition::decompose(self)
+    }
+
+    /// Uses Bareiss elimination when every entry is an integer and the rational LU decomposition otherwise
+    pub fn determinant(&self) -> Result<BigFraction, String> {
+        if self.is_integral() {
+            return Ok(BigFraction::from(self.det_bareiss()?));
+        }
+        Ok(self.lu_decomposition()?.det())
+    }
+
+    pub fn det_bareiss(&self) -> Result<BigInt, String> {
+        if !self.is_square() {
+            return Err(format!("Can not compute the determinant of a non square matrix {}x{}", self.row_count, self.col_count));
+        }
+        if !self.is_integral() {
+            return Err("Bareiss elimination is only defined for integer matrices".to_string());
+        }
+        Ok(bareiss::determinant(self.to_integer_rows()))
+    }
+
+    /// Fraction free row echelon form of an integer matrix, row `k` is scaled by the product of the previous pivots
+    pub fn echelon_bareiss(&self) -> Result<BigMatrix, String> {
+        if !self.is_integral() {
+            return Err("Bareiss elimination is only defined for integer matrices".to_string());
+        }
+        Ok(BigMatrix::from_integer_rows(&bareiss::echelon(self.to_integer_rows()).rows, self.col_count))
+    }
+
+    fn square_lu_decomposition(&self) -> Result<LUDecomposition, SolveError> {
+        if !self.is_square() {
+            return Err(SolveError::new(SolveErrorKind::NotSquare));
+        }
+        Ok(self.lu_decomposition().expect("Matrix is square"))
+    }
+
+    /// Solves `A·x = b` exactly
+    pub fn solve(&self, b: &BigVector) -> Result<BigVector, SolveError> {
+        self.square_lu_decomposition()?.solve(b)
+    }
+
+    /// Solves `A·X = B` exactly
+    pub fn solve_matrix(&self, b: &BigMatrix) -> Result<BigMatrix, SolveError> {
+        self.square_lu_decomposition()?.solve_matrix(b)
+    }
+
+    pub fn inverse(&self) -> Result<BigMatrix, SolveError> {
+        self.square_lu_decomposition()?.inverse()
+    }
+
+    /// Minimizes `|A·x - b|` exactly through the normal equations `A^T·A·x = A^T·b`
+    pub fn least_squares(&self, b: &BigVector) -> Result<BigVector, SolveError> {
+        if b.dimension() != self.row_count {
+            return Err(SolveError::new(SolveErrorKind::DimensionMismatch));
+        }
+        let transpose: BigMatrix = self.transpose();
+        let normal: BigMatrix = transpose.multiply(self).expect("Dimensions match");
+        let rhs: BigVector = transpose.multiply_vector(b).expect("Dimensions match");
+        normal.solve(&rhs).map_err(|e| match e.kind() {
+            SolveErrorKind::Singular => SolveError::new(SolveErrorKind::RankDeficient),
+            _ => e,
+        })
+    }
+
+    /// Gaussian elimination pivoting on the first nonzero entry, returns the echelon rows and the pivot columns
+    fn echelon(&self, reduced: bool) -> (Vec<Vec<BigFraction>>, Vec<usize>) {
+        let mut rows: Vec<Vec<BigFraction>> = self.to_row_vecs();
+        let mut pivots: Vec<usize> = Vec::new();
+        for col in 0..self.col_count {
+            let rank: usize = pivots.len();
+            let pivot: usize = match (rank..self.row_count).find(|&row| !rows[row][col].is_zero()) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            rows.swap(pivot, rank);
+            if reduced {
+                let inverse: BigFraction = rows[rank][col].clone().reciprocal().expect("Pivot is nonzero");
+                for value in rows[rank][col..].iter_mut() {
+                    *value = value.clone().mul(inverse.clone());
+                }
+            }
+            let pivot_row: Vec<BigFraction> = rows[rank].clone();
+            for (row, target) in rows.iter_mut().enumerate() {
+                if row == rank || (row < rank && !reduced) || target[col].is_zero() {
+                    continue;
+                }
+                let factor: BigFraction = target[col].clone().div(pivot_row[col].clone()).expect("Pivot is nonzero");
+                for (value, pivot_value) in target[col..].iter_mut().zip(pivot_row[col..].iter()) {
+                    *value = value.clone().sub(factor.clone().mul(pivot_value.clone()));
+                }
+            }
+            pivots.push(col);
+            if pivots.len() == self.row_count {
+                break;
+            }
+        }
+        (rows, pivots)
+    }
+
+    pub fn rank(&self) -> usize {
+        if self.is_integral() {
+            return bareiss::echelon(self.to_integer_rows()).pivots.len();
+        }
+        self.echelon(false).1.len()
+    }
+
+    pub fn row_echelon(&self) -> BigMatrix {
+        BigMatrix::from_row_vecs(self.echelon(false).0, self.col_count)
+    }
+
+    pub fn reduced_row_echelon(&self) -> BigMatrix {
+        BigMatrix::from_row_vecs(self.echelon(true).0, self.col_count)
+    }
+
+    /// Basis of the right null space `{x : A·x = 0}` given as rows
+    pub fn kernel_basis(&self) -> BigMatrix {
+        let (rows, pivots) = self.echelon(true);
+        let mut basis: Vec<Vec<BigFraction>> = Vec::new();
+        for free in (0..self.col_count).filter(|col| !pivots.contains(col)) {
+            let mut vector: Vec<BigFraction> = vec![BigFraction::get_zero(); self.col_count];
+            vector[free] = BigFraction::get_one();
+            for (row, &pivot) in pivots.iter().enumerate() {
+                vector[pivot] = rows[row][free].clone().negate();
+            }
+            basis.push(vector);
+        }
+        BigMatrix::from_row_vecs(basis, self.col_count)
+    }
+
+    /// Same span as `kernel_basis` with every vector scaled to a primitive integer vector, this is a basis
+    /// of the rational kernel and not necessarily of the integer lattice it contains
+    pub fn integral_kernel_basis(&self) -> BigMatrix {
+        let basis: Vec<Vec<BigFraction>> = self.kernel_basis().to_row_vecs().into_iter().map(|vector| {
+            let denominator: BigInt = vector.iter().fold(BigInt::one(), |lcm, x| lcm.lcm(&x.get_denominator()));
+            let scaled: Vec<BigInt> = vector.iter()
+                .map(|x| x.get_numerator() * (&denominator / x.get_denominator()))
+                .collect();
+            let gcd: BigInt = scaled.iter().fold(BigInt::zero(), |gcd, x| gcd.gcd(x));
+            scaled.iter().map(|x| BigFraction::from(x / &gcd)).collect()
+        }).collect();
+        BigMatrix::from_row_vecs(basis, self.col_count)
+    }
+
+    /// Basis of the column space `{A·x}` given as rows, taken from the pivot columns of `A`
+    pub fn image_basis(&self) -> BigMatrix {
+        let pivots: Vec<usize> = self.echelon(false).1;
+        let basis: Vec<Vec<BigFraction>> = pivots.iter()
+            .map(|&col| (0..self.row_count).map(|row| self.numbers[row * self.col_count + col].clone()).collect())
+            .collect();
+        BigMatrix::from_row_vecs(basis, self.row_count)
+    }
+
+    pub fn hermite_normal_form(&self, convention: HnfConvention) -> Result<HermiteNormalForm, String> {
+        HermiteNormalForm::decompose(self, convention)
+    }
+
+    pub fn smith_normal_form(&self) -> Result<SmithNormalForm, String> {
+        SmithNormalForm::decompose(self)
+    }
+
+    pub fn format(&self, style: VectorFormat) -> String {
+        let rows: Vec<String> = self.rows().iter().map(|row| row.format(style)).collect();
+        match style {
+            VectorFormat::LattiCG => format!("{{{}}}", rows.join(", ")),
+            VectorFormat::Sage => format!("[{}]", rows.join(", ")),
+            VectorFormat::Fplll => format!("[{}\n]", rows.join("\n")),
+        }
+    }
+}
+
+impl fmt::Display for BigMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(VectorFormat::LattiCG))
+    }
+}
@@ -1,6 +1,11 @@
 use crate::math::big_fraction::BigFraction;
+use core::fmt;
 use num_bigint::BigInt;
-use num_traits::Zero;
+use num_integer::{ExtendedGcd, Integer};
+use num_traits::{One, Signed, Zero};
+use std::error::Error;
+use std::ops::{Bound, RangeBounds};
+use std::str::FromStr;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct BigVector {
@@ -10,6 +15,73 @@ pub struct BigVector {
     step: usize,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorFormat {
+    /// `{1, 2/3, -4}` as printed by Java LattiCG
+    LattiCG,
+    /// `[1, 2/3, -4]` as printed by Sage and Python
+    Sage,
+    /// `[1 2/3 -4]` as read and printed by fplll
+    Fplll,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigVectorError {
+    kind: BigVectorErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BigVectorErrorKind {
+    Empty,
+    InvalidDelimiter,
+    InvalidEntry,
+    InvalidDimension,
+}
+
+impl ParseBigVectorError {
+    fn __description(&self) -> &str {
+        use self::BigVectorErrorKind::*;
+        match self.kind {
+            Empty => "cannot parse vector from empty string",
+            InvalidDelimiter => "vector must be enclosed in matching {} or []",
+            InvalidEntry => "invalid entry found in vector",
+            InvalidDimension => "vector does not have the expected dimension",
+        }
+    }
+
+    fn empty() -> Self {
+        ParseBigVectorError {
+            kind: BigVectorErrorKind::Empty,
+        }
+    }
+
+    fn invalid_delimiter() -> Self {
+        ParseBigVectorError {
+            kind: BigVectorErrorKind::InvalidDelimiter,
+        }
+    }
+
+    fn invalid_entry() -> Self {
+        ParseBigVectorError {
+            kind: BigVectorErrorKind::InvalidEntry,
+        }
+    }
+
+    pub(crate) fn invalid_dimension() -> Self {
+        ParseBigVectorError {
+            kind: BigVectorErrorKind::InvalidDimension,
+        }
+    }
+}
+
+impl fmt::Display for ParseBigVectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+impl Error for ParseBigVectorError {}
+
 impl BigVector {
     pub fn new(number: &[BigFraction]) -> BigVector {
         BigVector {
@@ -33,6 +105,95 @@ impl BigVector {
         }
     }
 
+    pub fn zeros(dimension: usize) -> BigVector {
+        BigVector::from(vec![BigFraction::get_zero(); dimension])
+    }
+
+    /// The unit vector `e_index`
+    pub fn basis(dimension: usize, index: usize) -> Result<BigVector, String> {
+        if index >= dimension {
+            return Err(format!("Index {}, dimension {}", index, dimension));
+        }
+        let mut vector: BigVector = BigVector::zeros(dimension);
+        vector.numbers[index] = BigFraction::get_one();
+        Ok(vector)
+    }
+
+    pub fn from_fn<F: FnMut(usize) -> BigFraction>(dimension: usize, f: F) -> BigVector {
+        BigVector::from((0..dimension).map(f).collect::<Vec<BigFraction>>())
+    }
+
+    pub fn to_vec(&self) -> Vec<BigFraction> {
+        self.iter().cloned().collect()
+    }
+
+    pub fn concat(&self, other: &BigVector) -> BigVector {
+        BigVector::from(self.iter().chain(other.iter()).cloned().collect::<Vec<BigFraction>>())
+    }
+
+    pub fn subvector<R: RangeBounds<usize>>(&self, range: R) -> Result<BigVector, String> {
+        let invalid = |range: String| format!("Range {} is not valid for dimension {}", range, self.dimension);
+        let start: usize = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).ok_or_else(|| invalid(format!("({})..", start)))?,
+            Bound::Unbounded => 0,
+        };
+        let end: usize = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).ok_or_else(|| invalid(format!("{}..={}", start, end)))?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.dimension,
+        };
+        if start > end || end > self.dimension {
+            return Err(invalid(format!("{}..{}", start, end)));
+        }
+        Ok(BigVector::from(self.iter().skip(start).take(end - start).cloned().collect::<Vec<BigFraction>>()))
+    }
+
+    /// Truncates or pads with zeros to the new dimension
+    pub fn resize(&mut self, dimension: usize) {
+        self.compact();
+        self.numbers.resize(dimension, BigFraction::get_zero());
+        self.dimension = dimension;
+    }
+
+    pub fn insert(&mut self, index: usize, value: BigFraction) -> Result<(), String> {
+        if index > self.dimension {
+            return Err(format!("Index {}, dimension {}", index, self.dimension));
+        }
+        self.compact();
+        self.numbers.insert(index, value);
+        self.dimension += 1;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, index: usize) -> Result<BigFraction, String> {
+        if index >= self.dimension {
+            return Err(format!("Index {}, dimension {}", index, self.dimension));
+        }
+        self.compact();
+        self.dimension -= 1;
+        Ok(self.numbers.remove(index))
+    }
+
+    pub fn map<F: FnMut(&BigFraction) -> BigFraction>(&self, f: F) -> BigVector {
+        BigVector::from(self.iter().map(f).collect::<Vec<BigFraction>>())
+    }
+
+    pub fn zip_with<F: FnMut(&BigFraction, &BigFraction) -> BigFraction>(&self, other: &BigVector, mut f: F) -> Result<BigVector, String> {
+        if self.dimension != other.dimension {
+            return Err(format!("The other vector ({}) is not the same dimension ({})", other.dimension, self.dimension));
+        }
+        Ok(BigVector::from(self.iter().zip(other.iter()).map(|(a, b)| f(a, b)).collect::<Vec<BigFraction>>()))
+    }
+
+    fn compact(&mut self) {
+        if self.start_pos != 0 || self.step != 1 || self.numbers.len() != self.dimension {
+            self.numbers = self.to_vec();
+            self.start_pos = 0;
+            self.step = 1;
+        }
+    }
+
     pub fn get(&self, index: usize) -> Result<BigFraction, String> {
         if index > self.dimension {
             return Err(format!("Index {}, dimension {}", index, self.dimension));
@@ -52,6 +213,21 @@ impl BigVector {
         return Ok(value);
     }
 
+    pub fn iter(&self) -> impl Iterator<Item=&BigFraction> {
+        self.numbers.iter().skip(self.start_pos).step_by(self.step).take(self.dimension)
+    }
+
+    pub fn dot(&self, other: &BigVector) -> Result<BigFraction, String> {
+        if self.dimension != other.dimension {
+            return Err(format!("The other vector ({}) is not the same dimension ({})", other.dimension, self.dimension));
+        }
+        let mut result: BigFraction = BigFraction::get_zero();
+        for (a, b) in self.iter().zip(other.iter()) {
+            result = result.add(a.clone().mul(b.clone()));
+        }
+        Ok(result)
+    }
+
     pub fn magnitude_sq(&self) -> BigFraction {
         let mut magnitude: BigFraction = BigFraction::get_zero();
         for i in 0..self.dimension {
@@ -60,6 +236,61 @@ impl BigVector {
         magnitude
     }
 
+    pub fn norm_l1(&self) -> BigFraction {
+        let mut norm: BigFraction = BigFraction::get_zero();
+        for value in self.iter() {
+            norm = norm.add(value.clone().abs());
+        }
+        norm
+    }
+
+    pub fn norm_linf(&self) -> BigFraction {
+        let mut norm: BigFraction = BigFraction::get_zero();
+        for value in self.iter() {
+            let value: BigFraction = value.clone().abs();
+            if value.clone().compare_to(norm.clone()) > 0 {
+                norm = value;
+            }
+        }
+        norm
+    }
+
+    /// Returns `(floor(|v|), ceil(|v|))`, the euclidean norm is only rational for perfect squares
+    pub fn norm_l2(&self) -> (BigInt, BigInt) {
+        let magnitude: BigFraction = self.magnitude_sq();
+        let lower: BigInt = magnitude.clone().floor().sqrt();
+        let upper_sq: BigInt = magnitude.ceil();
+        let mut upper: BigInt = upper_sq.sqrt();
+        if &upper * &upper < upper_sq {
+            upper += BigInt::one();
+        }
+        (lower, upper)
+    }
+
+    pub fn weighted_norm_sq(&self, weights: &BigVector) -> Result<BigFraction, String> {
+        if self.dimension != weights.dimension {
+            return Err(format!("The weights ({}) are not the same dimension ({})", weights.dimension, self.dimension));
+        }
+        let mut norm: BigFraction = BigFraction::get_zero();
+        for (value, weight) in self.iter().zip(weights.iter()) {
+            norm = norm.add(value.clone().mul(value.clone()).mul(weight.clone()));
+        }
+        Ok(norm)
+    }
+
+    /// Computes `v^T G v` where the gram matrix is given by its rows
+    pub fn quadratic_form(&self, gram_matrix: &[BigVector]) -> Result<BigFraction, String> {
+        if gram_matrix.len() != self.dimension {
+            return Err(format!("The gram matrix has {} rows but the vector has dimension {}", gram_matrix.len(), self.dimension));
+        }
+        let mut result: BigFraction = BigFraction::get_zero();
+        for (value, row) in self.iter().zip(gram_matrix.iter()) {
+            let dot: BigFraction = row.dot(self).map_err(|e| format!("The gram matrix is not square: {}", e))?;
+            result = result.add(value.clone().mul(dot));
+        }
+        Ok(result)
+    }
+
     pub fn is_zero(&self) -> bool {
         for i in 0..self.dimension {
             if self.get(i).unwrap().signum() != BigInt::zero() { return false; }
@@ -67,6 +298,61 @@ impl BigVector {
         true
     }
 
+    pub fn is_integral(&self) -> bool {
+        self.iter().all(|x| x.get_denominator().is_one())
+    }
+
+    /// The least common multiple `d` of all denominators together with the integral vector `d·self`
+    pub fn common_denominator(&self) -> (BigInt, BigVector) {
+        let denominator: BigInt = self.iter().fold(BigInt::one(), |lcm, x| lcm.lcm(&x.get_denominator()));
+        (denominator.clone(), self.map(|x| x.clone().mul_int(denominator.clone())))
+    }
+
+    fn integral_entries(&self, modulus: &BigInt) -> Result<Vec<BigInt>, String> {
+        if !modulus.is_positive() {
+            return Err(format!("The modulus {} is not positive", modulus));
+        }
+        if !self.is_integral() {
+            return Err(format!("The vector {} is not integral", self));
+        }
+        Ok(self.iter().map(|x| x.get_numerator()).collect())
+    }
+
+    /// Representatives in `[0, m)`
+    pub fn reduce_mod(&self, modulus: &BigInt) -> Result<BigVector, String> {
+        let entries: Vec<BigInt> = self.integral_entries(modulus)?;
+        Ok(BigVector::new_int(&entries.iter().map(|x| x.mod_floor(modulus)).collect::<Vec<BigInt>>()))
+    }
+
+    /// Representatives in `(-m/2, m/2]`
+    pub fn reduce_mod_centered(&self, modulus: &BigInt) -> Result<BigVector, String> {
+        let entries: Vec<BigInt> = self.integral_entries(modulus)?;
+        Ok(BigVector::new_int(&entries.iter().map(|x| {
+            let value: BigInt = x.mod_floor(modulus);
+            if &value * 2 > *modulus { value - modulus } else { value }
+        }).collect::<Vec<BigInt>>()))
+    }
+
+    pub fn is_congruent(&self, other: &BigVector, modulus: &BigInt) -> Result<bool, String> {
+        if self.dimension != other.dimension {
+            return Err(format!("The other vector ({}) is not the same dimension ({})", other.dimension, self.dimension));
+        }
+        let entries: Vec<BigInt> = self.integral_entries(modulus)?;
+        let other_entries: Vec<BigInt> = other.integral_entries(modulus)?;
+        Ok(entries.iter().zip(other_entries.iter()).all(|(a, b)| (a - b).mod_floor(modulus).is_zero()))
+    }
+
+    /// Multiplies by the inverse of `scalar` modulo `m`, reduced to `[0, m)`
+    pub fn mod_inverse_scale(&self, scalar: &BigInt, modulus: &BigInt) -> Result<BigVector, String> {
+        let entries: Vec<BigInt> = self.integral_entries(modulus)?;
+        let gcd: ExtendedGcd<BigInt> = scalar.extended_gcd(modulus);
+        if !gcd.gcd.is_one() {
+            return Err(format!("{} is not invertible modulo {}", scalar, modulus));
+        }
+        let inverse: BigInt = gcd.x.mod_floor(modulus);
+        Ok(BigVector::new_int(&entries.iter().map(|x| (x * &inverse).mod_floor(modulus)).collect::<Vec<BigInt>>()))
+    }
+
     pub fn add(&mut self, other: BigVector) -> Result<BigVector, String> {
         if self.dimension != other.dimension {
             return Err(format!("The other vector ({}) is not the same dimension ({})", self.dimension, other.dimension));
@@ -147,4 +433,71 @@ impl BigVector {
     pub fn dimension(&self) -> usize {
         self.dimension
     }
+
+    pub fn format(&self, style: VectorFormat) -> String {
+        format_entries(self.iter(), style)
+    }
+}
+
+pub(crate) fn format_entries<'a, I: Iterator<Item=&'a BigFraction>>(entries: I, style: VectorFormat) -> String {
+    let entries: Vec<String> = entries.map(|x| x.to_string()).collect();
+    match style {
+        VectorFormat::LattiCG => format!("{{{}}}", entries.join(", ")),
+        VectorFormat::Sage => format!("[{}]", entries.join(", ")),
+        VectorFormat::Fplll => format!("[{}]", entries.join(" ")),
+    }
+}
+
+impl From<Vec<BigFraction>> for BigVector {
+    fn from(numbers: Vec<BigFraction>) -> Self {
+        BigVector {
+            dimension: numbers.len(),
+            numbers,
+            start_pos: 0,
+            step: 1,
+        }
+    }
+}
+
+impl fmt::Display for BigVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(VectorFormat::LattiCG))
+    }
+}
+
+impl FromStr for BigVector {
+    type Err = ParseBigVectorError;
+
+    /// Accepts any of the `VectorFormat` notations, entries are separated by commas or whitespace
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_entries(s).map(BigVector::from)
+    }
+}
+
+/// Entries of a vector in any of the `VectorFormat` notations
+pub(crate) fn parse_entries(s: &str) -> Result<Vec<BigFraction>, ParseBigVectorError> {
+    let s: &str = s.trim();
+    if s.is_empty() {
+        return Err(ParseBigVectorError::empty());
+    }
+    let braces: bool = s.starts_with('{') && s.ends_with('}');
+    let brackets: bool = s.starts_with('[') && s.ends_with(']');
+    if s.len() < 2 || !(braces || brackets) {
+        return Err(ParseBigVectorError::invalid_delimiter());
+    }
+    let inner: &str = &s[1..s.len() - 1];
+    let entries: Vec<&str> = if inner.contains(',') {
+        inner.split(',').map(|x| x.trim()).collect()
+    } else {
+        inner.split_whitespace().collect()
+    };
+    let mut numbers: Vec<BigFraction> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        if entry.is_empty() || entry.contains(char::is_whitespace) {
+            return Err(ParseBigVectorError::invalid_entry());
+        }
+        let value: BigFraction = BigFraction::parse(entry.to_string()).map_err(|_| ParseBigVectorError::invalid_entry())?;
+        numbers.push(value);
+    }
+    Ok(numbers)
 }
@@ -0,0 +1,58 @@
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+
+/// Fraction free echelon form of an integer matrix, every entry stays a minor of the input so all the
+/// divisions by the previous pivot are exact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BareissEchelon {
+    pub(crate) rows: Vec<Vec<BigInt>>,
+    pub(crate) pivots: Vec<usize>,
+    pub(crate) swaps: usize,
+}
+
+pub(crate) fn echelon(mut rows: Vec<Vec<BigInt>>) -> BareissEchelon {
+    let row_count: usize = rows.len();
+    let col_count: usize = rows.first().map_or(0, |row| row.len());
+    let mut previous: BigInt = BigInt::one();
+    let mut pivots: Vec<usize> = Vec::new();
+    let mut swaps: usize = 0;
+    for col in 0..col_count {
+        let rank: usize = pivots.len();
+        if rank == row_count {
+            break;
+        }
+        let pivot: usize = match (rank..row_count).find(|&row| !rows[row][col].is_zero()) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        if pivot != rank {
+            rows.swap(pivot, rank);
+            swaps += 1;
+        }
+        let (upper, lower) = rows.split_at_mut(rank + 1);
+        let pivot_row: &[BigInt] = &upper[rank];
+        for row in lower.iter_mut() {
+            let factor: BigInt = row[col].clone();
+            for j in col + 1..col_count {
+                row[j] = (&pivot_row[col] * &row[j] - &factor * &pivot_row[j]) / &previous;
+            }
+            row[col] = BigInt::zero();
+        }
+        previous = pivot_row[col].clone();
+        pivots.push(col);
+    }
+    BareissEchelon { rows, pivots, swaps }
+}
+
+pub(crate) fn determinant(rows: Vec<Vec<BigInt>>) -> BigInt {
+    let size: usize = rows.len();
+    if size == 0 {
+        return BigInt::one();
+    }
+    let result: BareissEchelon = echelon(rows);
+    if result.pivots.len() < size {
+        return BigInt::zero();
+    }
+    let det: BigInt = result.rows[size - 1][size - 1].clone();
+    if result.swaps & 1 == 0 { det } else { -det }
+}
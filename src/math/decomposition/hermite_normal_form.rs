@@ -0,0 +1,276 @@
+use crate::math::big_matrix::BigMatrix;
+use crate::math::decomposition::bareiss::{self, BareissEchelon};
+use num_bigint::BigInt;
+use num_integer::{ExtendedGcd, Integer};
+use num_traits::{One, Signed, Zero};
+
+// past this many bits in an entry the modular method keeps intermediates bounded by the determinant
+const MODULAR_THRESHOLD_BITS: u64 = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HnfConvention {
+    /// `U·A = H`, the nonzero rows of `H` are an upper triangular basis of the row lattice
+    Row,
+    /// `A·U = H`, the nonzero columns of `H` are a lower triangular basis of the column lattice
+    Column,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HermiteNormalForm {
+    h: BigMatrix,
+    u: BigMatrix,
+    rank: usize,
+    convention: HnfConvention,
+}
+
+impl HermiteNormalForm {
+    pub fn decompose(matrix: &BigMatrix, convention: HnfConvention) -> Result<HermiteNormalForm, String> {
+        if !matrix.is_integral() {
+            return Err("The Hermite normal form is only defined for integer matrices".to_string());
+        }
+        let (rows, col_count): (Vec<Vec<BigInt>>, usize) = match convention {
+            HnfConvention::Row => (matrix.to_integer_rows(), matrix.col_count()),
+            HnfConvention::Column => (matrix.transpose().to_integer_rows(), matrix.row_count()),
+        };
+        let row_count: usize = rows.len();
+        let (h, u, rank) = match modular_echelon(&rows) {
+            Some(echelon) => row_hnf_modular(&rows, echelon),
+            None => row_hnf(rows),
+        };
+        let h: BigMatrix = BigMatrix::from_integer_rows(&h, col_count);
+        let u: BigMatrix = BigMatrix::from_integer_rows(&u, row_count);
+        Ok(match convention {
+            HnfConvention::Row => HermiteNormalForm { h, u, rank, convention },
+            HnfConvention::Column => HermiteNormalForm { h: h.transpose(), u: u.transpose(), rank, convention },
+        })
+    }
+
+    pub fn get_h(&self) -> &BigMatrix {
+        &self.h
+    }
+
+    /// Unimodular transformation, `U·A = H` for rows and `A·U = H` for columns
+    pub fn get_u(&self) -> &BigMatrix {
+        &self.u
+    }
+
+    pub fn get_rank(&self) -> usize {
+        self.rank
+    }
+
+    pub fn get_convention(&self) -> HnfConvention {
+        self.convention
+    }
+
+    /// The `rank` nonzero rows (or columns) of `H` as a basis given in rows
+    pub fn get_basis(&self) -> BigMatrix {
+        match self.convention {
+            HnfConvention::Row => self.h.submatrix(0, 0, self.rank, self.h.col_count()).expect("Rank fits"),
+            HnfConvention::Column => self.h.transpose().submatrix(0, 0, self.rank, self.h.row_count()).expect("Rank fits"),
+        }
+    }
+}
+
+/// Row style Hermite normal form of the full rank lattice spanned by the rows of `basis`, computed modulo
+/// `modulus` which must be a nonzero multiple of the lattice determinant. The determinant of an `n` dimensional
+/// q-ary lattice is some `q^k` with `k <= n`, so `q^n` is always valid while `q` alone only is when `k = 1`
+pub fn hermite_normal_form_mod_d(basis: &BigMatrix, modulus: &BigInt) -> Result<BigMatrix, String> {
+    if !basis.is_integral() {
+        return Err("The Hermite normal form is only defined for integer matrices".to_string());
+    }
+    if modulus.is_zero() {
+        return Err("The modulus must be a nonzero multiple of the determinant".to_string());
+    }
+    let rows: Vec<Vec<BigInt>> = basis.to_integer_rows();
+    Ok(BigMatrix::from_integer_rows(&hnf_mod_d(&rows, basis.col_count(), &modulus.abs()), basis.col_count()))
+}
+
+/// Fraction free elimination `T = M·A^T` of a nonsingular square matrix, `T` upper triangular
+struct TransposedEchelon {
+    t: Vec<Vec<BigInt>>,
+    m: Vec<Vec<BigInt>>,
+    determinant: BigInt,
+}
+
+/// The elimination the modular method needs when some entry is large and the matrix square and nonsingular,
+/// it yields the determinant and later the transformation without rational arithmetic
+fn modular_echelon(rows: &[Vec<BigInt>]) -> Option<TransposedEchelon> {
+    let size: usize = rows.len();
+    if size == 0 || rows.iter().any(|row| row.len() != size) {
+        return None;
+    }
+    if !rows.iter().flatten().any(|x| x.bits() > MODULAR_THRESHOLD_BITS) {
+        return None;
+    }
+    // Bareiss on [A^T | I], the right block records the row operations
+    let augmented: Vec<Vec<BigInt>> = (0..size)
+        .map(|i| (0..size).map(|j| rows[j][i].clone()).chain((0..size).map(|j| if i == j { BigInt::one() } else { BigInt::zero() })).collect())
+        .collect();
+    let echelon: BareissEchelon = bareiss::echelon(augmented);
+    if echelon.pivots.len() < size || echelon.pivots[size - 1] >= size {
+        return None;
+    }
+    let last: BigInt = echelon.rows[size - 1][size - 1].clone();
+    let determinant: BigInt = if echelon.swaps & 1 == 0 { last } else { -last };
+    let (t, m) = echelon.rows.into_iter().map(|mut row| {
+        let m: Vec<BigInt> = row.split_off(size);
+        (row, m)
+    }).unzip();
+    Some(TransposedEchelon { t, m, determinant })
+}
+
+/// Applies the unimodular `[[x, y], [-b/g, a/g]]` to rows `i` and `j` where `g = x·a + y·b = gcd(a, b)`
+pub(crate) fn combine_rows(rows: &mut [Vec<BigInt>], i: usize, j: usize, a: &BigInt, b: &BigInt) {
+    let ExtendedGcd { gcd, x, y, .. } = a.extended_gcd(b);
+    let (gcd, x, y) = if gcd.is_negative() { (-gcd, -x, -y) } else { (gcd, x, y) };
+    let p: BigInt = -(b / &gcd);
+    let q: BigInt = a / &gcd;
+    for col in 0..rows[i].len() {
+        let ri: BigInt = rows[i][col].clone();
+        let rj: BigInt = rows[j][col].clone();
+        rows[i][col] = &x * &ri + &y * &rj;
+        rows[j][col] = &p * &ri + &q * &rj;
+    }
+}
+
+pub(crate) fn add_row_multiple(rows: &mut [Vec<BigInt>], target: usize, source: usize, scalar: &BigInt) {
+    for col in 0..rows[target].len() {
+        let value: BigInt = scalar * &rows[source][col];
+        rows[target][col] += value;
+    }
+}
+
+pub(crate) fn negate_row(row: &mut [BigInt]) {
+    for value in row.iter_mut() {
+        *value = -value.clone();
+    }
+}
+
+pub(crate) fn identity_rows(size: usize) -> Vec<Vec<BigInt>> {
+    (0..size).map(|i| (0..size).map(|j| if i == j { BigInt::one() } else { BigInt::zero() }).collect()).collect()
+}
+
+/// Classical row style Hermite normal form through gcd row operations, returns `(H, U, rank)`
+fn row_hnf(mut h: Vec<Vec<BigInt>>) -> (Vec<Vec<BigInt>>, Vec<Vec<BigInt>>, usize) {
+    let row_count: usize = h.len();
+    let col_count: usize = h.first().map_or(0, |row| row.len());
+    let mut u: Vec<Vec<BigInt>> = identity_rows(row_count);
+    let mut rank: usize = 0;
+    for col in 0..col_count {
+        if rank == row_count {
+            break;
+        }
+        for row in rank + 1..row_count {
+            if h[row][col].is_zero() {
+                continue;
+            }
+            let a: BigInt = h[rank][col].clone();
+            let b: BigInt = h[row][col].clone();
+            combine_rows(&mut h, rank, row, &a, &b);
+            combine_rows(&mut u, rank, row, &a, &b);
+        }
+        if h[rank][col].is_zero() {
+            continue;
+        }
+        if h[rank][col].is_negative() {
+            negate_row(&mut h[rank]);
+            negate_row(&mut u[rank]);
+        }
+        for row in 0..rank {
+            let q: BigInt = h[row][col].div_floor(&h[rank][col]);
+            if !q.is_zero() {
+                add_row_multiple(&mut h, row, rank, &-q.clone());
+                add_row_multiple(&mut u, row, rank, &-q);
+            }
+        }
+        rank += 1;
+    }
+    (h, u, rank)
+}
+
+/// Reduces the entries above the diagonal of a square upper triangular matrix with positive diagonal
+fn reduce_above_diagonal(h: &mut [Vec<BigInt>]) {
+    for col in 1..h.len() {
+        for row in 0..col {
+            let q: BigInt = h[row][col].div_floor(&h[col][col]);
+            if !q.is_zero() {
+                add_row_multiple(h, row, col, &-q);
+            }
+        }
+    }
+}
+
+/// Domich, Kannan and Trotter: every `R·e_i` lies in the lattice so generators can be reduced modulo `R`,
+/// and once the pivot `h` of a column is known the sublattice of the next columns has determinant `R / h`
+fn hnf_mod_d(rows: &[Vec<BigInt>], dimension: usize, modulus: &BigInt) -> Vec<Vec<BigInt>> {
+    let mut modulus: BigInt = modulus.clone();
+    let mut generators: Vec<Vec<BigInt>> = rows.iter()
+        .map(|row| row.iter().map(|x| x.mod_floor(&modulus)).collect())
+        .collect();
+    let mut h: Vec<Vec<BigInt>> = vec![vec![BigInt::zero(); dimension]; dimension];
+    for col in 0..dimension {
+        generators.retain(|row| row.iter().any(|x| !x.is_zero()));
+        let pivot: Option<usize> = generators.iter().position(|row| !row[0].is_zero());
+        let mut g: Vec<BigInt> = match pivot {
+            Some(pivot) => {
+                let last: usize = generators.len() - 1;
+                generators.swap(pivot, last);
+                for row in 0..last {
+                    if !generators[row][0].is_zero() {
+                        let a: BigInt = generators[last][0].clone();
+                        let b: BigInt = generators[row][0].clone();
+                        combine_rows(&mut generators, last, row, &a, &b);
+                        for index in [last, row].iter() {
+                            for value in generators[*index].iter_mut() {
+                                *value = value.mod_floor(&modulus);
+                            }
+                        }
+                    }
+                }
+                generators.pop().expect("Pivot exists")
+            }
+            None => vec![BigInt::zero(); dimension - col],
+        };
+        let ExtendedGcd { gcd, x, .. } = g[0].extended_gcd(&modulus);
+        let (gcd, x) = if gcd.is_negative() { (-gcd, -x) } else { (gcd, x) };
+        let mut pivot_row: Vec<BigInt> = g.iter().map(|value| (&x * value).mod_floor(&modulus)).collect();
+        pivot_row[0] = gcd.clone();
+        let next_modulus: BigInt = &modulus / &gcd;
+        let g_factor: BigInt = &g[0] / &gcd;
+        for (value, pivot_value) in g.iter_mut().zip(pivot_row.iter()) {
+            *value -= &g_factor * pivot_value;
+        }
+        generators.push(g);
+        for row in generators.iter_mut() {
+            row.remove(0);
+            for value in row.iter_mut() {
+                *value = value.mod_floor(&next_modulus);
+            }
+        }
+        for (i, value) in pivot_row.into_iter().enumerate() {
+            h[col][col + i] = value;
+        }
+        modulus = next_modulus;
+    }
+    reduce_above_diagonal(&mut h);
+    h
+}
+
+/// Modular method for a square nonsingular matrix, the transformation solves `A^T·U^T = H^T` through the
+/// triangular `T·U^T = M·H^T` by back substitution, whose divisions are exact as `U` is integral
+fn row_hnf_modular(rows: &[Vec<BigInt>], echelon: TransposedEchelon) -> (Vec<Vec<BigInt>>, Vec<Vec<BigInt>>, usize) {
+    let size: usize = rows.len();
+    let h: Vec<Vec<BigInt>> = hnf_mod_d(rows, size, &echelon.determinant.abs());
+    let mut u: Vec<Vec<BigInt>> = vec![vec![BigInt::zero(); size]; size];
+    for (u_row, h_row) in u.iter_mut().zip(h.iter()) {
+        // each row of U is the matching column of U^T
+        for i in (0..size).rev() {
+            let mut value: BigInt = (0..size).fold(BigInt::zero(), |sum, l| sum + &echelon.m[i][l] * &h_row[l]);
+            for (t_value, u_value) in echelon.t[i].iter().zip(u_row.iter()).skip(i + 1) {
+                value -= t_value * u_value;
+            }
+            u_row[i] = value / &echelon.t[i][i];
+        }
+    }
+    (h, u, size)
+}
@@ -0,0 +1,79 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::big_vector::BigVector;
+
+/// `A = L·D·L^T` with `L` unit lower triangular and `D` diagonal, for the Gram matrix of a basis `L` holds
+/// the Gram-Schmidt coefficients `mu` and `D` the squared Gram-Schmidt norms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LDLDecomposition {
+    l: BigMatrix,
+    d: BigVector,
+}
+
+impl LDLDecomposition {
+    pub fn decompose(matrix: &BigMatrix) -> Result<LDLDecomposition, String> {
+        if !matrix.is_square() {
+            return Err(format!("Can not decompose a non square matrix {}x{}", matrix.row_count(), matrix.col_count()));
+        }
+        let size: usize = matrix.row_count();
+        let a: Vec<Vec<BigFraction>> = matrix.to_row_vecs();
+        let asymmetric: Option<(usize, usize)> = (0..size)
+            .flat_map(|row| (0..row).map(move |col| (row, col)))
+            .find(|&(row, col)| a[row][col] != a[col][row]);
+        if let Some((row, col)) = asymmetric {
+            return Err(format!("Can not decompose a non symmetric matrix, entries ({}, {}) and ({}, {}) differ", row, col, col, row));
+        }
+        let mut l: Vec<Vec<BigFraction>> = vec![vec![BigFraction::get_zero(); size]; size];
+        let mut d: Vec<BigFraction> = Vec::with_capacity(size);
+        for i in 0..size {
+            // r[j] = l[i][j] * d[j]
+            let mut r: Vec<BigFraction> = Vec::with_capacity(i);
+            for j in 0..i {
+                let mut value: BigFraction = a[i][j].clone();
+                for (k, r_k) in r.iter().enumerate() {
+                    value = value.sub(l[j][k].clone().mul(r_k.clone()));
+                }
+                l[i][j] = value.clone().div(d[j].clone()).expect("Previous pivots are positive");
+                r.push(value);
+            }
+            let mut pivot: BigFraction = a[i][i].clone();
+            for (k, r_k) in r.iter().enumerate() {
+                pivot = pivot.sub(l[i][k].clone().mul(r_k.clone()));
+            }
+            if pivot <= BigFraction::get_zero() {
+                return Err(format!("The matrix is not positive definite, pivot {} is {}", i, pivot.to_string()));
+            }
+            l[i][i] = BigFraction::get_one();
+            d.push(pivot);
+        }
+        Ok(LDLDecomposition {
+            l: BigMatrix::from_row_vecs(l, size),
+            d: BigVector::from(d),
+        })
+    }
+
+    pub fn get_l(&self) -> &BigMatrix {
+        &self.l
+    }
+
+    pub fn get_d(&self) -> &BigVector {
+        &self.d
+    }
+
+    /// The Gram-Schmidt coefficient `mu_ij = <b_i, b*_j> / <b*_j, b*_j>` for `j < i`
+    pub fn get_mu(&self, i: usize, j: usize) -> Result<BigFraction, String> {
+        if j >= i {
+            return Err(format!("mu_{}{} is only defined below the diagonal", i, j));
+        }
+        self.l.get(i, j)
+    }
+
+    /// The squared Gram-Schmidt norm `|b*_i|^2`
+    pub fn get_gso_norm_sq(&self, i: usize) -> Result<BigFraction, String> {
+        self.d.get(i)
+    }
+
+    pub fn det(&self) -> BigFraction {
+        self.d.iter().fold(BigFraction::get_one(), |det, value| det.mul(value.clone()))
+    }
+}
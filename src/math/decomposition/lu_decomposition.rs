@@ -0,0 +1,189 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::big_vector::BigVector;
+use core::fmt;
+use num_traits::Zero;
+use std::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    kind: SolveErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveErrorKind {
+    NotSquare,
+    DimensionMismatch,
+    Singular,
+    RankDeficient,
+}
+
+impl SolveError {
+    fn __description(&self) -> &str {
+        use self::SolveErrorKind::*;
+        match self.kind {
+            NotSquare => "the matrix is not square",
+            DimensionMismatch => "the right hand side does not match the matrix dimensions",
+            Singular => "the matrix is singular",
+            RankDeficient => "the matrix does not have full column rank",
+        }
+    }
+
+    pub fn kind(&self) -> SolveErrorKind {
+        self.kind
+    }
+
+    pub(crate) fn new(kind: SolveErrorKind) -> Self {
+        SolveError { kind }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+impl Error for SolveError {}
+
+/// `P·A = L·U` with `L` unit lower triangular and `U` upper triangular, the pivot of each
+/// column is the first nonzero entry at or below the diagonal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LUDecomposition {
+    l: BigMatrix,
+    u: BigMatrix,
+    permutation: Vec<usize>,
+    swaps: usize,
+}
+
+impl LUDecomposition {
+    pub fn decompose(matrix: &BigMatrix) -> Result<LUDecomposition, String> {
+        if !matrix.is_square() {
+            return Err(format!("Can not decompose a non square matrix {}x{}", matrix.row_count(), matrix.col_count()));
+        }
+        let size: usize = matrix.row_count();
+        let mut u: Vec<Vec<BigFraction>> = matrix.rows().iter().map(|row| row.to_vec()).collect();
+        let mut l: Vec<Vec<BigFraction>> = vec![vec![BigFraction::get_zero(); size]; size];
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut swaps: usize = 0;
+        for k in 0..size {
+            let pivot: Option<usize> = (k..size).find(|&row| !u[row][k].is_zero());
+            let pivot: usize = match pivot {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            if pivot != k {
+                u.swap(pivot, k);
+                l.swap(pivot, k);
+                permutation.swap(pivot, k);
+                swaps += 1;
+            }
+            for row in k + 1..size {
+                if u[row][k].is_zero() {
+                    continue;
+                }
+                let factor: BigFraction = u[row][k].clone().div(u[k][k].clone()).expect("Pivot is nonzero");
+                let (upper, lower) = u.split_at_mut(row);
+                for (target, value) in lower[0][k..].iter_mut().zip(upper[k][k..].iter()) {
+                    *target = target.clone().sub(factor.clone().mul(value.clone()));
+                }
+                l[row][k] = factor;
+            }
+        }
+        for (i, row) in l.iter_mut().enumerate() {
+            row[i] = BigFraction::get_one();
+        }
+        Ok(LUDecomposition {
+            l: BigMatrix::from_fn(size, size, |row, col| l[row][col].clone()),
+            u: BigMatrix::from_fn(size, size, |row, col| u[row][col].clone()),
+            permutation,
+            swaps,
+        })
+    }
+
+    pub fn get_l(&self) -> &BigMatrix {
+        &self.l
+    }
+
+    pub fn get_u(&self) -> &BigMatrix {
+        &self.u
+    }
+
+    /// Row `i` of `P·A` is row `permutation[i]` of `A`
+    pub fn get_permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn get_p(&self) -> BigMatrix {
+        let size: usize = self.permutation.len();
+        BigMatrix::from_fn(size, size, |row, col| if self.permutation[row] == col { BigFraction::get_one() } else { BigFraction::get_zero() })
+    }
+
+    pub fn get_swaps(&self) -> usize {
+        self.swaps
+    }
+
+    /// Sign of the permutation, `det(P)`
+    pub fn get_sign(&self) -> i32 {
+        if self.swaps & 1 == 0 { 1 } else { -1 }
+    }
+
+    pub fn is_singular(&self) -> bool {
+        (0..self.permutation.len()).any(|i| self.u.get(i, i).expect("Index in range").is_zero())
+    }
+
+    pub fn det(&self) -> BigFraction {
+        let mut det: BigFraction = BigFraction::from(self.get_sign() as i64);
+        for i in 0..self.permutation.len() {
+            det = det.mul(self.u.get(i, i).expect("Index in range"));
+        }
+        det
+    }
+
+    /// Solves `A·x = b`
+    pub fn solve(&self, b: &BigVector) -> Result<BigVector, SolveError> {
+        let size: usize = self.permutation.len();
+        if b.dimension() != size {
+            return Err(SolveError::new(SolveErrorKind::DimensionMismatch));
+        }
+        if self.is_singular() {
+            return Err(SolveError::new(SolveErrorKind::Singular));
+        }
+        let b: Vec<BigFraction> = b.to_vec();
+        let mut x: Vec<BigFraction> = Vec::with_capacity(size);
+        for row in 0..size {
+            let mut value: BigFraction = b[self.permutation[row]].clone();
+            for (col, y) in x.iter().enumerate() {
+                value = value.sub(self.l.get(row, col).expect("Index in range").mul(y.clone()));
+            }
+            x.push(value);
+        }
+        for row in (0..size).rev() {
+            let mut value: BigFraction = x[row].clone();
+            for (col, y) in x.iter().enumerate().skip(row + 1) {
+                value = value.sub(self.u.get(row, col).expect("Index in range").mul(y.clone()));
+            }
+            x[row] = value.div(self.u.get(row, row).expect("Index in range")).expect("Matrix is not singular");
+        }
+        Ok(BigVector::from(x))
+    }
+
+    /// Solves `A·X = B`
+    pub fn solve_matrix(&self, b: &BigMatrix) -> Result<BigMatrix, SolveError> {
+        if b.row_count() != self.permutation.len() {
+            return Err(SolveError::new(SolveErrorKind::DimensionMismatch));
+        }
+        let mut columns: Vec<BigVector> = Vec::with_capacity(b.col_count());
+        for column in b.columns() {
+            columns.push(self.solve(&column)?);
+        }
+        if columns.is_empty() {
+            return Ok(BigMatrix::zeros(b.row_count(), 0));
+        }
+        Ok(BigMatrix::from_columns(&columns).expect("Columns have the same dimension"))
+    }
+
+    pub fn inverse(&self) -> Result<BigMatrix, SolveError> {
+        self.solve_matrix(&BigMatrix::identity(self.permutation.len()))
+    }
+}
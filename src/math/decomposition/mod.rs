@@ -0,0 +1,5 @@
+pub mod bareiss;
+pub mod hermite_normal_form;
+pub mod ldl_decomposition;
+pub mod lu_decomposition;
+pub mod smith_normal_form;
@@ -0,0 +1,243 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::big_vector::BigVector;
+use crate::math::decomposition::hermite_normal_form::{add_row_multiple, combine_rows, identity_rows, negate_row};
+use num_bigint::BigInt;
+use num_integer::{ExtendedGcd, Integer};
+use num_traits::{One, Signed, Zero};
+
+/// `U·A·V = D` with `U`, `V` unimodular and `D` diagonal with `d_1 | d_2 | ... | d_r` positive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmithNormalForm {
+    d: BigMatrix,
+    u: BigMatrix,
+    v: BigMatrix,
+    rank: usize,
+}
+
+impl SmithNormalForm {
+    pub fn decompose(matrix: &BigMatrix) -> Result<SmithNormalForm, String> {
+        if !matrix.is_integral() {
+            return Err("The Smith normal form is only defined for integer matrices".to_string());
+        }
+        let (d, u, v, rank) = smith(matrix.to_integer_rows(), matrix.row_count(), matrix.col_count());
+        Ok(SmithNormalForm {
+            d: BigMatrix::from_integer_rows(&d, matrix.col_count()),
+            u: BigMatrix::from_integer_rows(&u, matrix.row_count()),
+            v: BigMatrix::from_integer_rows(&v, matrix.col_count()),
+            rank,
+        })
+    }
+
+    pub fn get_d(&self) -> &BigMatrix {
+        &self.d
+    }
+
+    pub fn get_u(&self) -> &BigMatrix {
+        &self.u
+    }
+
+    pub fn get_v(&self) -> &BigMatrix {
+        &self.v
+    }
+
+    pub fn get_rank(&self) -> usize {
+        self.rank
+    }
+
+    /// The nonzero diagonal entries of `D`
+    pub fn get_diagonal(&self) -> Vec<BigInt> {
+        (0..self.rank).map(|i| self.d.get(i, i).expect("Index in range").get_numerator()).collect()
+    }
+}
+
+fn combine_columns(rows: &mut [Vec<BigInt>], i: usize, j: usize, a: &BigInt, b: &BigInt) {
+    let ExtendedGcd { gcd, x, y, .. } = a.extended_gcd(b);
+    let (gcd, x, y) = if gcd.is_negative() { (-gcd, -x, -y) } else { (gcd, x, y) };
+    let p: BigInt = -(b / &gcd);
+    let q: BigInt = a / &gcd;
+    for row in rows.iter_mut() {
+        let ci: BigInt = row[i].clone();
+        let cj: BigInt = row[j].clone();
+        row[i] = &x * &ci + &y * &cj;
+        row[j] = &p * &ci + &q * &cj;
+    }
+}
+
+fn swap_columns(rows: &mut [Vec<BigInt>], i: usize, j: usize) {
+    for row in rows.iter_mut() {
+        row.swap(i, j);
+    }
+}
+
+type IntegerRows = Vec<Vec<BigInt>>;
+
+fn smith(mut a: IntegerRows, row_count: usize, col_count: usize) -> (IntegerRows, IntegerRows, IntegerRows, usize) {
+    let mut u: IntegerRows = identity_rows(row_count);
+    let mut v: IntegerRows = identity_rows(col_count);
+    let mut rank: usize = 0;
+    for t in 0..row_count.min(col_count) {
+        loop {
+            let mut pivot: Option<(usize, usize)> = None;
+            for (i, row) in a.iter().enumerate().skip(t) {
+                for (j, value) in row.iter().enumerate().skip(t) {
+                    if value.is_zero() {
+                        continue;
+                    }
+                    let smaller: bool = match pivot {
+                        Some((pi, pj)) => value.abs() < a[pi][pj].abs(),
+                        None => true,
+                    };
+                    if smaller {
+                        pivot = Some((i, j));
+                    }
+                }
+            }
+            let (pivot_row, pivot_col) = match pivot {
+                Some(pivot) => pivot,
+                None => return (a, u, v, rank),
+            };
+            a.swap(t, pivot_row);
+            u.swap(t, pivot_row);
+            swap_columns(&mut a, t, pivot_col);
+            swap_columns(&mut v, t, pivot_col);
+            for i in t + 1..row_count {
+                if !a[i][t].is_zero() {
+                    let (x, y) = (a[t][t].clone(), a[i][t].clone());
+                    combine_rows(&mut a, t, i, &x, &y);
+                    combine_rows(&mut u, t, i, &x, &y);
+                }
+            }
+            for j in t + 1..col_count {
+                if !a[t][j].is_zero() {
+                    let (x, y) = (a[t][t].clone(), a[t][j].clone());
+                    combine_columns(&mut a, t, j, &x, &y);
+                    combine_columns(&mut v, t, j, &x, &y);
+                }
+            }
+            if (t + 1..row_count).any(|i| !a[i][t].is_zero()) {
+                continue;
+            }
+            let pivot_value: BigInt = a[t][t].clone();
+            let not_divisible: Option<usize> = (t + 1..row_count)
+                .find(|&i| a[i][t + 1..].iter().any(|value| !value.is_multiple_of(&pivot_value)));
+            match not_divisible {
+                Some(i) => {
+                    add_row_multiple(&mut a, t, i, &BigInt::one());
+                    add_row_multiple(&mut u, t, i, &BigInt::one());
+                }
+                None => break,
+            }
+        }
+        if a[t][t].is_negative() {
+            negate_row(&mut a[t]);
+            negate_row(&mut u[t]);
+        }
+        rank += 1;
+    }
+    (a, u, v, rank)
+}
+
+/// The abelian group `Z^n / L` where `L` is spanned by the rows of a generator matrix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuotientGroup {
+    dimension: usize,
+    invariant_factors: Vec<BigInt>,
+    free_rank: usize,
+    v: BigMatrix,
+    v_inverse: BigMatrix,
+    offset: usize,
+}
+
+impl QuotientGroup {
+    pub fn new(generators: &BigMatrix) -> Result<QuotientGroup, String> {
+        let snf: SmithNormalForm = SmithNormalForm::decompose(generators)?;
+        let diagonal: Vec<BigInt> = snf.get_diagonal();
+        let offset: usize = diagonal.iter().take_while(|d| d.is_one()).count();
+        let dimension: usize = generators.col_count();
+        Ok(QuotientGroup {
+            dimension,
+            invariant_factors: diagonal[offset..].to_vec(),
+            free_rank: dimension - snf.get_rank(),
+            v_inverse: snf.get_v().inverse().expect("V is unimodular"),
+            v: snf.v,
+            offset,
+        })
+    }
+
+    /// The factors `d_i > 1` with `Z^n / L = Z/d_1 x ... x Z/d_k x Z^free_rank` and `d_i | d_(i+1)`
+    pub fn invariant_factors(&self) -> &[BigInt] {
+        &self.invariant_factors
+    }
+
+    pub fn free_rank(&self) -> usize {
+        self.free_rank
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.free_rank == 0
+    }
+
+    /// The index of `L` in `Z^n`, `None` when the quotient is infinite
+    pub fn order(&self) -> Option<BigInt> {
+        if !self.is_finite() {
+            return None;
+        }
+        Some(self.invariant_factors.iter().fold(BigInt::one(), |order, d| order * d))
+    }
+
+    /// Coordinates of the class of `x` in `Z/d_1 x ... x Z/d_k x Z^free_rank`, equal exactly when `x - y` lies in `L`
+    pub fn coordinates(&self, x: &BigVector) -> Result<Vec<BigInt>, String> {
+        if x.dimension() != self.dimension || !x.is_integral() {
+            return Err(format!("Expected an integer vector of dimension {}", self.dimension));
+        }
+        let y: BigVector = self.v.transpose().multiply_vector(x).expect("Dimensions match");
+        let factors: usize = self.invariant_factors.len();
+        Ok(y.iter().skip(self.offset).enumerate().map(|(i, value)| {
+            if i < factors { value.get_numerator().mod_floor(&self.invariant_factors[i]) } else { value.get_numerator() }
+        }).collect())
+    }
+
+    /// Iterates over one representative of every coset of `L`, only possible when the quotient is finite
+    pub fn coset_representatives(&self) -> Result<CosetRepresentatives<'_>, String> {
+        if !self.is_finite() {
+            return Err(format!("The quotient has free rank {} and infinitely many cosets", self.free_rank));
+        }
+        Ok(CosetRepresentatives {
+            group: self,
+            counter: vec![BigInt::zero(); self.invariant_factors.len()],
+            done: false,
+        })
+    }
+}
+
+pub struct CosetRepresentatives<'a> {
+    group: &'a QuotientGroup,
+    counter: Vec<BigInt>,
+    done: bool,
+}
+
+impl<'a> Iterator for CosetRepresentatives<'a> {
+    type Item = BigVector;
+
+    fn next(&mut self) -> Option<BigVector> {
+        if self.done {
+            return None;
+        }
+        let mut y: Vec<BigFraction> = vec![BigFraction::get_zero(); self.group.dimension];
+        for (i, value) in self.counter.iter().enumerate() {
+            y[self.group.offset + i] = BigFraction::from(value.clone());
+        }
+        let representative: BigVector = self.group.v_inverse.transpose().multiply_vector(&BigVector::from(y)).expect("Dimensions match");
+        self.done = true;
+        for (value, d) in self.counter.iter_mut().zip(self.group.invariant_factors.iter()) {
+            *value += 1;
+            if *value < *d {
+                self.done = false;
+                break;
+            }
+            *value = BigInt::zero();
+        }
+        Some(representative)
+    }
+}
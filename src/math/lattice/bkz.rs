@@ -0,0 +1,308 @@
+use crate::math::big_matrix::BigMatrix;
+use crate::math::decomposition::hermite_normal_form::identity_rows;
+use crate::math::lattice::enumeration::{self, Enumeration, EnumerationStats};
+use crate::math::lattice::gso::{self, FloatGso, GsoParts};
+use crate::math::lattice::lll::{self, LLLParams, LLLResult};
+use crate::math::lattice::lll_fp;
+use num_bigint::BigInt;
+use num_integer::{ExtendedGcd, Integer};
+use num_traits::{One, Signed, Zero};
+use std::fmt;
+use std::sync::Arc;
+
+/// Pruning coefficients for the enumeration of a block, `coefficients(n)[d]` is the fraction of the squared
+/// radius available once the top `d + 1` levels are fixed, non decreasing and ending in 1
+pub trait Pruning: fmt::Debug {
+    fn coefficients(&self, block_size: usize) -> Vec<f64>;
+}
+
+/// Full enumeration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoPruning;
+
+impl Pruning for NoPruning {
+    fn coefficients(&self, block_size: usize) -> Vec<f64> {
+        vec![1.0; block_size]
+    }
+}
+
+/// Linear pruning of Gama, Nguyen and Regev, the bound grows linearly with the number of fixed levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearPruning;
+
+impl Pruning for LinearPruning {
+    fn coefficients(&self, block_size: usize) -> Vec<f64> {
+        (1..=block_size).map(|d| d as f64 / block_size as f64).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BKZParams {
+    block_size: usize,
+    lll: LLLParams,
+    max_tours: Option<usize>,
+    auto_abort: Option<(usize, f64)>,
+    gh_factor: Option<f64>,
+    node_limit: Option<u64>,
+    pruning: Arc<dyn Pruning + Send + Sync>,
+}
+
+impl BKZParams {
+    pub fn new(block_size: usize) -> BKZParams {
+        BKZParams {
+            block_size,
+            lll: LLLParams::new(),
+            max_tours: None,
+            auto_abort: Some((5, 1e-3)),
+            gh_factor: None,
+            node_limit: None,
+            pruning: Arc::new(NoPruning),
+        }
+    }
+
+    /// Parameters of the LLL calls, `delta` also decides when an enumerated vector is worth inserting
+    pub fn with_lll(mut self, lll: LLLParams) -> BKZParams {
+        self.lll = lll;
+        self
+    }
+
+    pub fn with_max_tours(mut self, max_tours: usize) -> BKZParams {
+        self.max_tours = Some(max_tours);
+        self
+    }
+
+    /// Stops once `tours` consecutive tours improve the slope of the Gram-Schmidt log profile by less than
+    /// `threshold` relative to the best slope so far, `None` only stops when a tour changes nothing
+    pub fn with_auto_abort(mut self, auto_abort: Option<(usize, f64)>) -> BKZParams {
+        self.auto_abort = auto_abort;
+        self
+    }
+
+    /// Caps the enumeration radius at `factor` times the Gaussian heuristic of the block, BKZ 2.0 uses 1.1 for
+    /// large blocks where the heuristic is accurate
+    pub fn with_gh_factor(mut self, gh_factor: Option<f64>) -> BKZParams {
+        self.gh_factor = gh_factor;
+        self
+    }
+
+    /// Maximum number of enumeration nodes per block, the best vector found so far is still used
+    pub fn with_node_limit(mut self, node_limit: Option<u64>) -> BKZParams {
+        self.node_limit = node_limit;
+        self
+    }
+
+    pub fn with_pruning(mut self, pruning: Arc<dyn Pruning + Send + Sync>) -> BKZParams {
+        self.pruning = pruning;
+        self
+    }
+
+    pub fn get_block_size(&self) -> usize {
+        self.block_size
+    }
+
+    pub fn get_lll(&self) -> &LLLParams {
+        &self.lll
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.lll.validate()?;
+        if self.block_size < 2 {
+            return Err(format!("The block size must be at least 2, got {}", self.block_size));
+        }
+        if let Some(factor) = self.gh_factor {
+            if factor.is_nan() || factor <= 0.0 {
+                return Err(format!("The Gaussian heuristic factor must be positive, got {}", factor));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TourStats {
+    pub tour: usize,
+    pub root_hermite_factor: f64,
+    /// `|b_1|`
+    pub first_norm: f64,
+    pub insertions: usize,
+    pub nodes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BKZResult {
+    lll: LLLResult,
+    tours: Vec<TourStats>,
+}
+
+impl BKZResult {
+    pub fn get_reduced_basis(&self) -> &BigMatrix {
+        self.lll.get_reduced_basis()
+    }
+
+    /// Unimodular `U` with `U·B` equal to the reduced basis followed by zero rows, one per dependency
+    pub fn get_transformations(&self) -> &BigMatrix {
+        self.lll.get_transformations()
+    }
+
+    pub fn get_rank(&self) -> usize {
+        self.lll.get_rank()
+    }
+
+    pub fn get_tour_stats(&self) -> &[TourStats] {
+        &self.tours
+    }
+}
+
+/// BKZ 2.0 reduction of the lattice spanned by the rows of `basis`: an LLL reduced basis is improved block by
+/// block with the shortest vector of each projected block, found by pruned enumeration in `f64` below the
+/// Gaussian heuristic bound, until a tour changes nothing, progress stalls or the tour limit is reached
+pub fn bkz(basis: &BigMatrix, params: &BKZParams) -> Result<BKZResult, String> {
+    params.validate()?;
+    let (denominator, scaled) = basis.common_denominator();
+    let rows: Vec<Vec<BigInt>> = scaled.to_integer_rows();
+    let size: usize = rows.len();
+    let mut parts: GsoParts = lll_fp::reduce((rows, identity_rows(size), Vec::new()), &params.lll, None, true);
+    let rank: usize = parts.0.len();
+    let delta: f64 = params.lll.get_delta().to_double();
+    let ln_denominator: f64 = gso::ln_int(&denominator);
+    let mut tours: Vec<TourStats> = Vec::new();
+    let mut best_slope: Option<f64> = None;
+    let mut stalled: usize = 0;
+    while rank >= 2 && !params.max_tours.iter().any(|&max_tours| tours.len() >= max_tours) {
+        let mut insertions: usize = 0;
+        let mut nodes: u64 = 0;
+        let mut gso: FloatGso = gso::float_gso(&parts.0);
+        for k in 0..rank - 1 {
+            let end: usize = (k + params.block_size).min(rank);
+            let (coefficients, stats) = enumerate_block(&gso, k, end, delta, params);
+            nodes += stats.nodes;
+            if let Some(coefficients) = coefficients {
+                insert(&mut parts, k, coefficients);
+                parts = reduce_prefix(parts, end, &params.lll);
+                gso = gso::float_gso(&parts.0);
+                insertions += 1;
+            }
+        }
+        let stats: TourStats = tour_stats(&gso, ln_denominator, tours.len(), insertions, nodes);
+        tours.push(stats);
+        if insertions == 0 {
+            break;
+        }
+        if let Some((max_stalled, threshold)) = params.auto_abort {
+            let slope: f64 = profile_slope(&gso);
+            match best_slope {
+                Some(best) if slope <= best + threshold * best.abs() => stalled += 1,
+                _ => stalled = 0,
+            }
+            best_slope = Some(best_slope.map_or(slope, |best| best.max(slope)));
+            if stalled >= max_stalled {
+                break;
+            }
+        }
+    }
+    let parts: GsoParts = lll_fp::reduce(parts, &params.lll, None, true);
+    Ok(BKZResult { lll: lll::to_result(&parts, &denominator, basis.col_count()), tours })
+}
+
+/// Shortest combination of the projected block `b_k..b_{end-1}` when it beats `delta·|b*_k|^2`
+fn enumerate_block(gso: &FloatGso, k: usize, end: usize, delta: f64, params: &BKZParams) -> (Option<Vec<BigInt>>, EnumerationStats) {
+    let mu: Vec<Vec<f64>> = (k..end).map(|i| (k..end).map(|j| gso.mu[i][j]).collect()).collect();
+    let b: &[f64] = &gso.b[k..end];
+    let pruning: Vec<f64> = params.pruning.coefficients(end - k);
+    let mut radius: f64 = delta * b[0];
+    if let Some(factor) = params.gh_factor {
+        radius = radius.min(factor * factor * enumeration::gaussian_heuristic_sq(b));
+    }
+    let mut best: Option<Vec<BigInt>> = None;
+    let stats: EnumerationStats = Enumeration::new(&mu, b, &pruning, radius, params.node_limit).run(|coefficients, norm| {
+        best = Some(coefficients.to_vec());
+        Some(*norm)
+    });
+    // the first block vector itself is no improvement
+    match best {
+        Some(x) if x[0].abs().is_one() && x[1..].iter().all(|c| c.is_zero()) => (None, stats),
+        best => (best, stats),
+    }
+}
+
+/// Replaces the block starting at `k` by a unimodular transform of it whose first row is `sum_i x_i·b_{k+i}`
+fn insert(parts: &mut GsoParts, k: usize, mut x: Vec<BigInt>) {
+    let gcd: BigInt = x.iter().fold(BigInt::zero(), |gcd, value| gcd.gcd(value));
+    for value in x.iter_mut() {
+        *value /= &gcd;
+    }
+    let pivot: usize = x.iter().position(|value| !value.is_zero()).expect("Nonzero combination");
+    for j in pivot + 1..x.len() {
+        if x[j].is_zero() {
+            continue;
+        }
+        // [[a/g, b/g], [-y, x]] is unimodular and folds a·r_p + b·r_j into g·r_p
+        let ExtendedGcd { gcd, x: s, y: t, .. } = x[pivot].extended_gcd(&x[j]);
+        let coefficients: [BigInt; 4] = [&x[pivot] / &gcd, &x[j] / &gcd, -t, s];
+        combine(&mut parts.0, k + pivot, k + j, &coefficients);
+        combine(&mut parts.1, k + pivot, k + j, &coefficients);
+        x[pivot] = gcd;
+        x[j] = BigInt::zero();
+    }
+    if x[pivot].is_negative() {
+        for rows in [&mut parts.0, &mut parts.1].iter_mut() {
+            for value in rows[k + pivot].iter_mut() {
+                *value = -value.clone();
+            }
+        }
+    }
+    parts.0[k..=k + pivot].rotate_right(1);
+    parts.1[k..=k + pivot].rotate_right(1);
+}
+
+/// LLL on the rows before the block end `end` and the row right after it as in BKZ 2.0, the later rows are
+/// untouched by an insertion
+fn reduce_prefix(parts: GsoParts, end: usize, params: &LLLParams) -> GsoParts {
+    let (mut basis, mut transform, relations) = parts;
+    let prefix: usize = (end + 1).min(basis.len());
+    let basis_tail: Vec<Vec<BigInt>> = basis.split_off(prefix);
+    let transform_tail: Vec<Vec<BigInt>> = transform.split_off(prefix);
+    // the insertion is unimodular so the prefix stays independent and no relation appears
+    let (mut basis, mut transform, _) = lll_fp::reduce((basis, transform, Vec::new()), params, None, false);
+    basis.extend(basis_tail);
+    transform.extend(transform_tail);
+    (basis, transform, relations)
+}
+
+/// `(r_i, r_j) <- (a·r_i + b·r_j, c·r_i + d·r_j)`
+fn combine(rows: &mut [Vec<BigInt>], i: usize, j: usize, [a, b, c, d]: &[BigInt; 4]) {
+    for col in 0..rows[i].len() {
+        let ri: BigInt = rows[i][col].clone();
+        let rj: BigInt = rows[j][col].clone();
+        rows[i][col] = a * &ri + b * &rj;
+        rows[j][col] = c * &ri + d * &rj;
+    }
+}
+
+/// Least squares slope of `ln |b*_i|^2` against `i`, flatter profiles have slopes closer to zero
+fn profile_slope(gso: &FloatGso) -> f64 {
+    let n: usize = gso.b.len();
+    let mean_i: f64 = (n as f64 - 1.0) / 2.0;
+    let values: Vec<f64> = (0..n).map(|i| gso.ln_norm_sq(i)).collect();
+    let mean_v: f64 = values.iter().sum::<f64>() / n as f64;
+    let (mut numerator, mut denominator): (f64, f64) = (0.0, 0.0);
+    for (i, value) in values.iter().enumerate() {
+        numerator += (i as f64 - mean_i) * (value - mean_v);
+        denominator += (i as f64 - mean_i) * (i as f64 - mean_i);
+    }
+    numerator / denominator
+}
+
+/// Statistics of the basis scaled back by the common denominator, which the root Hermite factor is invariant to
+fn tour_stats(gso: &FloatGso, ln_denominator: f64, tour: usize, insertions: usize, nodes: u64) -> TourStats {
+    let n: usize = gso.b.len();
+    let ln_first: f64 = gso.ln_norm_sq(0) / 2.0 - ln_denominator;
+    let ln_volume: f64 = (0..n).map(|i| gso.ln_norm_sq(i)).sum::<f64>() / 2.0 - n as f64 * ln_denominator;
+    TourStats {
+        tour,
+        root_hermite_factor: ((ln_first - ln_volume / n as f64) / n as f64).exp(),
+        first_norm: ln_first.exp(),
+        insertions,
+        nodes,
+    }
+}
@@ -0,0 +1,93 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::big_vector::BigVector;
+use crate::math::decomposition::hermite_normal_form::{hermite_normal_form_mod_d, HermiteNormalForm, HnfConvention};
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+
+/// Kannan's embedding of the closest vector problem for `target` into a shortest vector problem: the rows
+/// `(b_i, 0)` and `(target, factor)`. A short vector `±(target - v, factor)` reveals the lattice vector `v`
+/// close to the target, the factor is usually around the expected distance
+pub fn kannan_embedding(basis: &BigMatrix, target: &BigVector, factor: &BigFraction) -> Result<BigMatrix, String> {
+    if basis.col_count() != target.dimension() {
+        return Err(format!("The target has dimension {} but the basis has dimension {}", target.dimension(), basis.col_count()));
+    }
+    if factor.is_zero() {
+        return Err("The embedding factor must be nonzero".to_string());
+    }
+    let rows: usize = basis.row_count();
+    let cols: usize = basis.col_count();
+    Ok(BigMatrix::from_fn(rows + 1, cols + 1, |row, col| match (row < rows, col < cols) {
+        (true, true) => basis.get(row, col).expect("Entry in range"),
+        (true, false) => BigFraction::get_zero(),
+        (false, true) => target.get(col).expect("Entry in range"),
+        (false, false) => factor.clone(),
+    }))
+}
+
+/// The q-ary lattice `{y : y = x·A mod q}` spanned by the rows of the integer matrix `A` and `q·Z^n`, as its
+/// Hermite normal form basis. The powers `(1, a, a^2, ...)` of an LCG multiplier modulo `m` give the lattice
+/// of its consecutive states
+pub fn q_ary_lattice(matrix: &BigMatrix, q: &BigInt) -> Result<BigMatrix, String> {
+    check_modulus(q)?;
+    let (rows, cols) = (matrix.row_count(), matrix.col_count());
+    let generators: BigMatrix = BigMatrix::from_fn(rows + cols, cols, |row, col| {
+        if row < rows {
+            matrix.get(row, col).expect("Entry in range")
+        } else if row - rows == col {
+            BigFraction::from(q.clone())
+        } else {
+            BigFraction::get_zero()
+        }
+    });
+    hermite_normal_form_mod_d(&generators, &index_bound(q, cols))
+}
+
+/// The lattice `{x : A·x = 0 mod m}` of the solutions of a system of congruences, as its Hermite normal form basis
+pub fn kernel_lattice_mod(matrix: &BigMatrix, modulus: &BigInt) -> Result<BigMatrix, String> {
+    check_modulus(modulus)?;
+    if !matrix.is_integral() {
+        return Err("The congruences must have integer coefficients".to_string());
+    }
+    // x is a solution exactly when (x, -A·x / m) lies in the integer kernel of [A | m·I]
+    let (rows, cols) = (matrix.row_count(), matrix.col_count());
+    let extended: BigMatrix = BigMatrix::from_fn(rows, cols + rows, |row, col| {
+        if col < cols {
+            matrix.get(row, col).expect("Entry in range")
+        } else if col - cols == row {
+            BigFraction::from(modulus.clone())
+        } else {
+            BigFraction::get_zero()
+        }
+    });
+    let kernel: BigMatrix = integer_kernel(&extended)?;
+    let solutions: BigMatrix = kernel.submatrix(0, 0, kernel.row_count(), cols)?;
+    hermite_normal_form_mod_d(&solutions, &index_bound(modulus, cols))
+}
+
+/// The lattice of the integer vectors orthogonal to every row of `vectors`, as its Hermite normal form basis
+pub fn orthogonal_lattice(vectors: &BigMatrix) -> Result<BigMatrix, String> {
+    // scaling a row by its denominator keeps the orthogonal complement
+    let (_, scaled) = vectors.row_denominators();
+    let kernel: BigMatrix = integer_kernel(&scaled)?;
+    Ok(HermiteNormalForm::decompose(&kernel, HnfConvention::Row)?.get_basis())
+}
+
+fn check_modulus(modulus: &BigInt) -> Result<(), String> {
+    if !modulus.is_positive() {
+        return Err(format!("The modulus must be positive, got {}", modulus));
+    }
+    Ok(())
+}
+
+/// A lattice containing `m·Z^n` has an index dividing `m^n`, a valid modulus for the Hermite normal form
+fn index_bound(modulus: &BigInt, dimension: usize) -> BigInt {
+    modulus.pow(dimension as u32)
+}
+
+/// Basis of `{x in Z^n : M·x = 0}` in rows, the rows of `U` with `U·M^T = H` that map to the zero rows of `H`
+fn integer_kernel(matrix: &BigMatrix) -> Result<BigMatrix, String> {
+    let hnf: HermiteNormalForm = HermiteNormalForm::decompose(&matrix.transpose(), HnfConvention::Row)?;
+    let size: usize = matrix.col_count();
+    hnf.get_u().submatrix(hnf.get_rank(), 0, size - hnf.get_rank(), size)
+}
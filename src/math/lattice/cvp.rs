@@ -0,0 +1,116 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::big_vector::BigVector;
+use crate::math::decomposition::lu_decomposition::SolveErrorKind;
+use crate::math::lattice::enumeration::Enumeration;
+use crate::math::lattice::gso;
+use num_bigint::BigInt;
+
+/// A lattice vector close to a target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CVPSolution {
+    vector: BigVector,
+    coefficients: BigVector,
+    distance_sq: BigFraction,
+}
+
+impl CVPSolution {
+    fn new(basis: &BigMatrix, coefficients: &[BigInt], target: &BigVector) -> CVPSolution {
+        let coefficients: BigVector = BigVector::new_int(coefficients);
+        let vector: BigVector = basis.transpose().multiply_vector(&coefficients).expect("Dimensions match");
+        let distance_sq: BigFraction = vector.zip_with(target, |a, b| a.clone() - b.clone()).expect("Dimensions match").magnitude_sq();
+        CVPSolution { vector, coefficients, distance_sq }
+    }
+
+    pub fn get_vector(&self) -> &BigVector {
+        &self.vector
+    }
+
+    /// Integer coefficients of the vector in the rows of the basis
+    pub fn get_coefficients(&self) -> &BigVector {
+        &self.coefficients
+    }
+
+    /// Squared distance to the target
+    pub fn get_distance_sq(&self) -> &BigFraction {
+        &self.distance_sq
+    }
+}
+
+/// Babai's rounding: the real coefficients of the projection of `target` onto the span of the linearly
+/// independent rows of `basis`, rounded to the nearest integers. Cheap but only good on a very orthogonal basis
+pub fn babai_round(basis: &BigMatrix, target: &BigVector) -> Result<CVPSolution, String> {
+    check_target(basis, target)?;
+    let real: BigVector = basis.transpose().least_squares(target).map_err(|error| match error.kind() {
+        SolveErrorKind::RankDeficient => "The basis rows must be linearly independent".to_string(),
+        _ => error.to_string(),
+    })?;
+    let coefficients: Vec<BigInt> = real.iter().map(|x| x.clone().round()).collect();
+    Ok(CVPSolution::new(basis, &coefficients, target))
+}
+
+/// Babai's nearest plane: from the last Gram-Schmidt vector down, picks the closest hyperplane of the lattice
+/// at each level. On an LLL reduced basis the distance is within `2^(n/2)` of the closest vector
+pub fn babai_nearest_plane(basis: &BigMatrix, target: &BigVector) -> Result<CVPSolution, String> {
+    check_target(basis, target)?;
+    let (mu, b) = gso::exact_gso(basis)?;
+    let mut center: Vec<BigFraction> = gso_coordinates(basis, &mu, &b, target);
+    let mut coefficients: Vec<BigInt> = vec![BigInt::from(0); b.len()];
+    for k in (0..b.len()).rev() {
+        coefficients[k] = center[k].clone().round();
+        let x: BigFraction = BigFraction::from(coefficients[k].clone());
+        for j in 0..k {
+            center[j] = center[j].clone() - x.clone() * mu[k][j].clone();
+        }
+    }
+    Ok(CVPSolution::new(basis, &coefficients, target))
+}
+
+/// Every lattice vector of the linearly independent rows of `basis` with squared distance to `target` at most
+/// `radius_sq`, closest first. Enumerates exactly around the target in Gram-Schmidt coordinates, the default
+/// radius is the distance of the nearest plane solution so the closest vectors are always listed
+pub fn cvp_enumerate(basis: &BigMatrix, target: &BigVector, radius_sq: Option<&BigFraction>) -> Result<Vec<CVPSolution>, String> {
+    check_target(basis, target)?;
+    let radius: BigFraction = match radius_sq {
+        Some(radius) if *radius < BigFraction::get_zero() => return Err(format!("The radius must not be negative, got {}", radius.to_string())),
+        Some(radius) => radius.clone(),
+        None => babai_nearest_plane(basis, target)?.distance_sq,
+    };
+    let (mu, b) = gso::exact_gso(basis)?;
+    let center: Vec<BigFraction> = gso_coordinates(basis, &mu, &b, target);
+    // the part of the target orthogonal to the lattice adds to every distance
+    let orthogonal: BigFraction = center.iter().zip(b.iter())
+        .fold(target.magnitude_sq(), |rest, (y, norm)| rest - y.clone() * y.clone() * norm.clone());
+    if radius < orthogonal {
+        return Ok(Vec::new());
+    }
+    let pruning: Vec<BigFraction> = vec![BigFraction::get_one(); b.len()];
+    let mut solutions: Vec<CVPSolution> = Vec::new();
+    Enumeration::new(&mu, &b, &pruning, radius - orthogonal, None).with_target(&center).run(|coefficients, _| {
+        solutions.push(CVPSolution::new(basis, coefficients, target));
+        None
+    });
+    if b.is_empty() {
+        solutions.push(CVPSolution::new(basis, &[], target));
+    }
+    solutions.sort_by(|a, b| a.distance_sq.cmp(&b.distance_sq));
+    Ok(solutions)
+}
+
+fn check_target(basis: &BigMatrix, target: &BigVector) -> Result<(), String> {
+    if basis.col_count() != target.dimension() {
+        return Err(format!("The target has dimension {} but the basis has dimension {}", target.dimension(), basis.col_count()));
+    }
+    Ok(())
+}
+
+/// Coordinates `y` of the projection of `target` onto the span of the rows as `sum_i y_i·b*_i`
+fn gso_coordinates(basis: &BigMatrix, mu: &[Vec<BigFraction>], b: &[BigFraction], target: &BigVector) -> Vec<BigFraction> {
+    let mut y: Vec<BigFraction> = Vec::with_capacity(b.len());
+    for (i, row) in basis.rows().iter().enumerate() {
+        // <t, b*_i> = <t, b_i> - sum_j mu_ij·<t, b*_j>
+        let dot: BigFraction = (0..i).fold(target.dot(row).expect("Dimensions match"), |dot, j| dot - mu[i][j].clone() * b[j].clone() * y[j].clone());
+        y.push(dot / b[i].clone());
+    }
+    y
+}
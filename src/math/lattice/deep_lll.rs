@@ -0,0 +1,39 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::decomposition::hermite_normal_form::identity_rows;
+use crate::math::lattice::gso::{self, GsoParts, GsoState};
+use crate::math::lattice::lll::{self, LLLParams, LLLResult};
+use crate::math::lattice::lll_fp;
+use num_bigint::BigInt;
+
+/// Deep insertion LLL of Schnorr and Euchner in exact rational arithmetic: `b_k` is inserted at the first
+/// position `i` where `delta·|b*_i|^2 > |pi_i(b_k)|^2`, checking the positions `i < depth` and `i >= k - depth`.
+/// Depth 1 only adds the first position to the Lovász condition, a depth of at least the rank checks them all.
+/// The result is LLL reduced and usually noticeably shorter, at a cost between LLL and BKZ
+pub fn deep_lll(basis: &BigMatrix, params: &LLLParams, depth: usize) -> Result<LLLResult, String> {
+    params.validate_delta()?;
+    validate_depth(depth)?;
+    let (denominator, scaled) = basis.common_denominator();
+    let mut state: GsoState<BigFraction> = GsoState::new(scaled.to_integer_rows(), 0);
+    gso::lll_reduce(&mut state, params.get_delta(), &BigFraction::get_half(), Some(depth), None).expect("Exact arithmetic does not fail");
+    Ok(lll::to_result(&state.into_parts(), &denominator, basis.col_count()))
+}
+
+/// Deep insertion LLL with the floating point Gram-Schmidt coefficients and precision ladder of `lll_fp`, the
+/// returned basis is verified to be LLL reduced exactly while the deep insertion conditions hold up to rounding
+pub fn deep_lll_fp(basis: &BigMatrix, params: &LLLParams, depth: usize) -> Result<LLLResult, String> {
+    params.validate()?;
+    validate_depth(depth)?;
+    let (denominator, scaled) = basis.common_denominator();
+    let rows: Vec<Vec<BigInt>> = scaled.to_integer_rows();
+    let size: usize = rows.len();
+    let parts: GsoParts = lll_fp::reduce((rows, identity_rows(size), Vec::new()), params, Some(depth), true);
+    Ok(lll::to_result(&parts, &denominator, basis.col_count()))
+}
+
+fn validate_depth(depth: usize) -> Result<(), String> {
+    if depth == 0 {
+        return Err("The insertion depth must be at least 1".to_string());
+    }
+    Ok(())
+}
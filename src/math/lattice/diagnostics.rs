@@ -0,0 +1,135 @@
+use crate::math::big_float::BigFloat;
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::lattice::enumeration;
+use crate::math::lattice::gso;
+use crate::math::lattice::lll::LLLParams;
+use num_bigint::BigInt;
+
+/// A basis of linearly independent rows with its exact Gram-Schmidt data, for judging the quality of a basis
+/// and estimating the cost of an enumeration. Floating point quantities are computed through logarithms and
+/// do not overflow before the final result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lattice {
+    basis: BigMatrix,
+    mu: Vec<Vec<BigFraction>>,
+    b: Vec<BigFraction>,
+}
+
+impl Lattice {
+    pub fn new(basis: BigMatrix) -> Result<Lattice, String> {
+        if basis.row_count() == 0 {
+            return Err("The basis must have at least one row".to_string());
+        }
+        let (mu, b) = gso::exact_gso(&basis)?;
+        Ok(Lattice { basis, mu, b })
+    }
+
+    pub fn get_basis(&self) -> &BigMatrix {
+        &self.basis
+    }
+
+    pub fn rank(&self) -> usize {
+        self.b.len()
+    }
+
+    /// The Gram-Schmidt coefficient `mu_ij` for `j < i`
+    pub fn get_mu(&self, i: usize, j: usize) -> Result<BigFraction, String> {
+        if i >= self.rank() || j >= i {
+            return Err(format!("mu_{}{} is only defined below the diagonal of a rank {} basis", i, j, self.rank()));
+        }
+        Ok(self.mu[i][j].clone())
+    }
+
+    /// The squared Gram-Schmidt norms `|b*_i|^2`
+    pub fn get_gso_norms_sq(&self) -> &[BigFraction] {
+        &self.b
+    }
+
+    /// The exact squared volume, the determinant of the Gram matrix
+    pub fn determinant_sq(&self) -> BigFraction {
+        self.b.iter().fold(BigFraction::get_one(), |det, value| det * value.clone())
+    }
+
+    /// The volume `prod_i |b*_i|` rounded to `precision` bits, with an exponent unbounded unlike `f64`
+    pub fn determinant(&self, precision: u32) -> BigFloat {
+        let det_sq: BigFraction = self.determinant_sq();
+        let denominator: BigInt = det_sq.get_denominator();
+        // sqrt(p / q) = sqrt(p·q·4^s) / (q·2^s) with enough bits in the integer square root
+        let shift: usize = precision as usize + 1;
+        let root: BigInt = ((det_sq.get_numerator() * &denominator) << (2 * shift)).sqrt();
+        let volume: BigFraction = BigFraction::new(root, denominator << shift).expect("Nonzero denominator");
+        BigFloat::from_fraction(&volume, precision)
+    }
+
+    /// The natural logarithm of the volume, finite for any basis
+    pub fn ln_determinant(&self) -> f64 {
+        self.ln_volume()
+    }
+
+    /// `log2 |b*_i|` for every `i`, a reduced basis has a profile decreasing roughly linearly
+    pub fn gso_log_profile(&self) -> Vec<f64> {
+        self.b.iter().map(|value| ln(value) / 2.0 / std::f64::consts::LN_2).collect()
+    }
+
+    /// The expected length `(vol / V_n)^(1/n)` of the shortest vector of a random lattice of this volume
+    pub fn gaussian_heuristic(&self) -> f64 {
+        let n: usize = self.rank();
+        ((self.ln_volume() - enumeration::ln_ball_volume(n)) / n as f64).exp()
+    }
+
+    /// `(|b_1| / vol^(1/n))^(1/n)`, about 1.0219 for LLL and 1.012 for BKZ-20 in high dimension
+    pub fn root_hermite_factor(&self) -> f64 {
+        let n: f64 = self.rank() as f64;
+        let ln_first: f64 = ln(&self.basis.get_row(0).expect("Nonempty basis").magnitude_sq()) / 2.0;
+        ((ln_first - self.ln_volume() / n) / n).exp()
+    }
+
+    /// `prod_i |b_i| / vol`, at least 1 with equality exactly for an orthogonal basis
+    pub fn orthogonality_defect(&self) -> f64 {
+        let ln_lengths: f64 = self.basis.rows().iter().map(|row| ln(&row.magnitude_sq()) / 2.0).sum();
+        (ln_lengths - self.ln_volume()).exp()
+    }
+
+    /// Whether every `|mu_ij| <= eta`
+    pub fn is_size_reduced(&self, eta: &BigFraction) -> bool {
+        self.mu.iter().enumerate().all(|(i, row)| row[..i].iter().all(|mu| mu.clone().abs() <= *eta))
+    }
+
+    /// Whether the basis is size reduced for `eta` and satisfies the Lovász conditions for `delta`, checked exactly.
+    /// Takes both parameters rather than `delta` alone as floating point reductions only reach `eta > 1/2`, so a
+    /// basis from `lll_fp` or an outside tool is checked against the `eta` it was reduced with
+    pub fn is_lll_reduced(&self, params: &LLLParams) -> bool {
+        // scaling by the common denominator changes neither the coefficients nor the norm ratios
+        let (_, scaled) = self.basis.common_denominator();
+        gso::is_lll_reduced(&scaled.to_integer_rows(), params.get_delta(), params.get_eta())
+    }
+
+    /// Gaussian heuristic estimate `V_n·r^n / vol` of the number of lattice vectors of length at most `radius`
+    pub fn expected_points(&self, radius: f64) -> f64 {
+        let n: usize = self.rank();
+        (enumeration::ln_ball_volume(n) + n as f64 * radius.ln() - self.ln_volume()).exp()
+    }
+
+    /// Gaussian heuristic estimate of the nodes of a full enumeration of radius `radius`, level `k` holds about
+    /// half the points of the projected lattice spanned by the last `k` Gram-Schmidt vectors within the radius
+    pub fn expected_enumeration_nodes(&self, radius: f64) -> f64 {
+        let n: usize = self.rank();
+        let mut ln_volume: f64 = 0.0;
+        let mut nodes: f64 = 0.0;
+        for k in 1..=n {
+            ln_volume += ln(&self.b[n - k]) / 2.0;
+            nodes += (enumeration::ln_ball_volume(k) + k as f64 * radius.ln() - ln_volume).exp() / 2.0;
+        }
+        nodes
+    }
+
+    fn ln_volume(&self) -> f64 {
+        self.b.iter().map(ln).sum::<f64>() / 2.0
+    }
+}
+
+/// Natural logarithm of a positive fraction of any size
+fn ln(value: &BigFraction) -> f64 {
+    gso::ln_int(&value.get_numerator()) - gso::ln_int(&value.get_denominator())
+}
@@ -0,0 +1,132 @@
+use crate::math::lattice::gso::GsoNumber;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+
+/// Outcome of a walk through the enumeration tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct EnumerationStats {
+    pub(crate) nodes: u64,
+    /// `false` when the walk stopped at the node limit
+    pub(crate) complete: bool,
+}
+
+/// Depth first Schnorr-Euchner enumeration of the integer combinations `x` with
+/// `sum_i (x_i + sum_{j>i} x_j·mu_ji)^2·b_i <= radius`, children are visited in zig-zag order around their center
+/// so a level is left as soon as one child exceeds its bound. `pruning[d]` scales the bound once the top `d + 1`
+/// levels are fixed, and only one of `x` and `-x` is visited unless a target is set
+pub(crate) struct Enumeration<'a, T> {
+    mu: &'a [Vec<T>],
+    b: &'a [T],
+    pruning: &'a [T],
+    target: Option<&'a [T]>,
+    bounds: Vec<T>,
+    x: Vec<T>,
+    node_limit: Option<u64>,
+    nodes: u64,
+}
+
+impl<'a, T: GsoNumber> Enumeration<'a, T> {
+    pub(crate) fn new(mu: &'a [Vec<T>], b: &'a [T], pruning: &'a [T], radius: T, node_limit: Option<u64>) -> Enumeration<'a, T> {
+        let zero: T = T::from_int(&BigInt::zero(), 0);
+        let mut enumeration: Enumeration<'a, T> = Enumeration {
+            mu,
+            b,
+            pruning,
+            target: None,
+            bounds: vec![zero.clone(); b.len()],
+            x: vec![zero; b.len()],
+            node_limit,
+            nodes: 0,
+        };
+        enumeration.set_radius(radius);
+        enumeration
+    }
+
+    /// Enumerates around `sum_i target_i·b*_i` instead of the origin, `x_i` is then centered at `target_i` less the
+    /// contribution of the levels above and every solution including zero is visited
+    pub(crate) fn with_target(mut self, target: &'a [T]) -> Enumeration<'a, T> {
+        self.target = Some(target);
+        self
+    }
+
+    fn set_radius(&mut self, radius: T) {
+        let size: usize = self.b.len();
+        for i in 0..size {
+            self.bounds[i] = radius.clone() * self.pruning[size - 1 - i].clone();
+        }
+    }
+
+    /// Calls `visit` with the coefficients and squared norm of every nonzero solution, a returned radius
+    /// replaces the current one
+    pub(crate) fn run<F: FnMut(&[BigInt], &T) -> Option<T>>(mut self, mut visit: F) -> EnumerationStats {
+        let size: usize = self.b.len();
+        let symmetric: bool = self.target.is_none();
+        let complete: bool = size == 0 || self.search(size - 1, T::from_int(&BigInt::zero(), 0), symmetric, &mut visit);
+        EnumerationStats { nodes: self.nodes, complete }
+    }
+
+    fn search<F: FnMut(&[BigInt], &T) -> Option<T>>(&mut self, level: usize, partial: T, zero_above: bool, visit: &mut F) -> bool {
+        let one: T = T::from_int(&BigInt::one(), 0);
+        let mut center: T = match self.target {
+            Some(target) => target[level].clone(),
+            None => T::from_int(&BigInt::zero(), 0),
+        };
+        for j in level + 1..self.b.len() {
+            center = center - self.x[j].clone() * self.mu[j][level].clone();
+        }
+        let mut x: T = T::from_int(&center.to_nearest_int(), 0);
+        // zig-zag step, the first move goes towards the center
+        let mut step: T = if center >= x { one.clone() } else { -one.clone() };
+        loop {
+            self.nodes += 1;
+            if let Some(limit) = self.node_limit {
+                if self.nodes > limit {
+                    return false;
+                }
+            }
+            let difference: T = x.clone() - center.clone();
+            let distance: T = partial.clone() + difference.clone() * difference * self.b[level].clone();
+            if distance > self.bounds[level] {
+                return true;
+            }
+            self.x[level] = x.clone();
+            let is_zero: bool = zero_above && x.to_nearest_int().is_zero();
+            if level == 0 {
+                if !is_zero {
+                    let coefficients: Vec<BigInt> = self.x.iter().map(|value| value.to_nearest_int()).collect();
+                    if let Some(radius) = visit(&coefficients, &distance) {
+                        self.set_radius(radius);
+                    }
+                }
+            } else if !self.search(level - 1, distance, is_zero, visit) {
+                return false;
+            }
+            if zero_above {
+                // the center is zero, only the non negative half is visited
+                x = x + one.clone();
+            } else {
+                x = x + step.clone();
+                step = if step > T::from_int(&BigInt::zero(), 0) { -(step + one.clone()) } else { -step + one.clone() };
+            }
+        }
+    }
+}
+
+/// `ln` of the volume of the unit ball in dimension `n`, `pi^(n/2) / Gamma(n/2 + 1)`
+pub(crate) fn ln_ball_volume(n: usize) -> f64 {
+    // Gamma(n/2 + 1) through the recurrence from Gamma(1) = 1 or Gamma(1/2) = sqrt(pi)
+    let mut ln_gamma: f64 = if n & 1 == 0 { 0.0 } else { 0.5 * std::f64::consts::PI.ln() };
+    let mut k: usize = 2 - (n & 1);
+    while k <= n {
+        ln_gamma += (k as f64 / 2.0).ln();
+        k += 2;
+    }
+    n as f64 / 2.0 * std::f64::consts::PI.ln() - ln_gamma
+}
+
+/// Squared Gaussian heuristic `(vol / V_n)^(2/n)` of a lattice given its squared Gram-Schmidt norms
+pub(crate) fn gaussian_heuristic_sq(b: &[f64]) -> f64 {
+    let n: usize = b.len();
+    let ln_volume: f64 = b.iter().map(|value| value.ln()).sum::<f64>() / 2.0;
+    (2.0 * (ln_volume - ln_ball_volume(n)) / n as f64).exp()
+}
@@ -0,0 +1,393 @@
+use crate::math::big_float::BigFloat;
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::decomposition::hermite_normal_form::identity_rows;
+use crate::math::decomposition::ldl_decomposition::LDLDecomposition;
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Number type the Gram-Schmidt coefficients are computed in, `precision` is only used by multiprecision floats
+pub(crate) trait GsoNumber: Clone + PartialOrd + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Neg<Output=Self> {
+    fn from_int(value: &BigInt, precision: u32) -> Self;
+
+    fn from_fraction(value: &BigFraction, precision: u32) -> Self;
+
+    /// The nearest integer
+    fn to_nearest_int(&self) -> BigInt;
+
+    fn is_finite(&self) -> bool;
+}
+
+impl GsoNumber for BigFraction {
+    fn from_int(value: &BigInt, _precision: u32) -> Self {
+        BigFraction::from(value.clone())
+    }
+
+    fn from_fraction(value: &BigFraction, _precision: u32) -> Self {
+        value.clone()
+    }
+
+    fn to_nearest_int(&self) -> BigInt {
+        self.clone().round()
+    }
+
+    fn is_finite(&self) -> bool {
+        true
+    }
+}
+
+impl GsoNumber for f64 {
+    fn from_int(value: &BigInt, _precision: u32) -> Self {
+        value.to_f64().unwrap_or(if value.is_negative() { f64::NEG_INFINITY } else { f64::INFINITY })
+    }
+
+    fn from_fraction(value: &BigFraction, _precision: u32) -> Self {
+        value.to_double()
+    }
+
+    fn to_nearest_int(&self) -> BigInt {
+        BigInt::from_f64(self.round()).expect("Value is finite")
+    }
+
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+impl GsoNumber for BigFloat {
+    fn from_int(value: &BigInt, precision: u32) -> Self {
+        BigFloat::from_int(value, precision)
+    }
+
+    fn from_fraction(value: &BigFraction, precision: u32) -> Self {
+        BigFloat::from_fraction(value, precision)
+    }
+
+    fn to_nearest_int(&self) -> BigInt {
+        self.round()
+    }
+
+    fn is_finite(&self) -> bool {
+        true
+    }
+}
+
+/// Ways a floating point reduction can lose track of the lattice, the caller retries with more precision
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GsoFailure {
+    NonFinite,
+    SizeReductionLoop,
+    IterationLimit,
+}
+
+// lazy size reduction passes before the precision is declared insufficient
+const MAX_SIZE_REDUCTION_PASSES: usize = 64;
+
+/// Basis, transformation and relations of a reduction in progress
+pub(crate) type GsoParts = (Vec<Vec<BigInt>>, Vec<Vec<BigInt>>, Vec<Vec<BigInt>>);
+
+/// Integer basis with its exact Gram matrix and the Cholesky data `r_ij = <b_i, b*_j>`, `mu_ij = r_ij / r_jj`
+/// computed row by row from the Gram matrix, rows `0..k` are valid while row `k` is being processed
+pub(crate) struct GsoState<T> {
+    pub(crate) basis: Vec<Vec<BigInt>>,
+    pub(crate) transform: Vec<Vec<BigInt>>,
+    /// Transformation rows of the vectors removed as zero, each maps the input to the zero vector
+    pub(crate) relations: Vec<Vec<BigInt>>,
+    pub(crate) gram: Vec<Vec<BigInt>>,
+    pub(crate) r: Vec<Vec<T>>,
+    pub(crate) mu: Vec<Vec<T>>,
+    pub(crate) precision: u32,
+}
+
+impl<T: GsoNumber> GsoState<T> {
+    pub(crate) fn new(basis: Vec<Vec<BigInt>>, precision: u32) -> GsoState<T> {
+        let transform: Vec<Vec<BigInt>> = identity_rows(basis.len());
+        GsoState::resume((basis, transform, Vec::new()), precision)
+    }
+
+    /// Continues from the basis, transformation and relations of an earlier state, possibly in another number type
+    pub(crate) fn resume(parts: GsoParts, precision: u32) -> GsoState<T> {
+        let (basis, transform, relations) = parts;
+        let size: usize = basis.len();
+        let gram: Vec<Vec<BigInt>> = (0..size)
+            .map(|i| (0..size).map(|j| dot(&basis[i], &basis[j])).collect())
+            .collect();
+        let zero: T = T::from_int(&BigInt::zero(), precision);
+        GsoState {
+            basis,
+            transform,
+            relations,
+            gram,
+            r: vec![vec![zero.clone(); size]; size],
+            mu: vec![vec![zero; size]; size],
+            precision,
+        }
+    }
+
+    pub(crate) fn into_parts(self) -> GsoParts {
+        (self.basis, self.transform, self.relations)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.basis.len()
+    }
+
+    pub(crate) fn compute_row(&mut self, k: usize) -> Result<(), GsoFailure> {
+        for j in 0..=k {
+            let mut value: T = T::from_int(&self.gram[k][j], self.precision);
+            for l in 0..j {
+                value = value - self.mu[j][l].clone() * self.r[k][l].clone();
+            }
+            if !value.is_finite() {
+                return Err(GsoFailure::NonFinite);
+            }
+            if j < k {
+                self.mu[k][j] = value.clone() / self.r[j][j].clone();
+                if !self.mu[k][j].is_finite() {
+                    return Err(GsoFailure::NonFinite);
+                }
+            }
+            self.r[k][j] = value;
+        }
+        Ok(())
+    }
+
+    /// Size reduces `b_k` against `b_0..b_{k-1}` until every `|mu_kj| <= eta` and leaves row `k` computed
+    pub(crate) fn size_reduce(&mut self, k: usize, eta: &T) -> Result<(), GsoFailure> {
+        for _ in 0..MAX_SIZE_REDUCTION_PASSES {
+            self.compute_row(k)?;
+            if (0..k).all(|j| abs(&self.mu[k][j]) <= *eta) {
+                return Ok(());
+            }
+            for j in (0..k).rev() {
+                let x: BigInt = self.mu[k][j].to_nearest_int();
+                if x.is_zero() {
+                    continue;
+                }
+                self.sub_row_multiple(k, j, &x);
+                let factor: T = T::from_int(&x, self.precision);
+                for l in 0..=j {
+                    let value: T = if l == j { T::from_int(&BigInt::one(), self.precision) } else { self.mu[j][l].clone() };
+                    self.mu[k][l] = self.mu[k][l].clone() - factor.clone() * value;
+                }
+            }
+        }
+        Err(GsoFailure::SizeReductionLoop)
+    }
+
+    /// `b_k -= x·b_j`, keeping the Gram matrix and the transformation in sync
+    pub(crate) fn sub_row_multiple(&mut self, k: usize, j: usize, x: &BigInt) {
+        sub_multiple(&mut self.basis, k, j, x);
+        sub_multiple(&mut self.transform, k, j, x);
+        let g_kk: BigInt = &self.gram[k][k] - BigInt::from(2) * x * &self.gram[k][j] + x * x * &self.gram[j][j];
+        for i in 0..self.len() {
+            if i != k {
+                let value: BigInt = &self.gram[k][i] - x * &self.gram[j][i];
+                self.gram[k][i] = value.clone();
+                self.gram[i][k] = value;
+            }
+        }
+        self.gram[k][k] = g_kk;
+    }
+
+    /// Moves row `from` to position `to <= from`, shifting the rows in between down by one
+    pub(crate) fn move_row(&mut self, from: usize, to: usize) {
+        self.basis[to..=from].rotate_right(1);
+        self.transform[to..=from].rotate_right(1);
+        self.gram[to..=from].rotate_right(1);
+        for row in self.gram.iter_mut() {
+            row[to..=from].rotate_right(1);
+        }
+    }
+
+    pub(crate) fn swap(&mut self, k: usize) {
+        self.move_row(k, k - 1);
+    }
+
+    /// Drops the zero vector `b_k` and records its transformation row as a relation
+    pub(crate) fn remove(&mut self, k: usize) {
+        self.basis.remove(k);
+        self.relations.push(self.transform.remove(k));
+        self.gram.remove(k);
+        for row in self.gram.iter_mut() {
+            row.remove(k);
+        }
+        self.r.pop();
+        self.mu.pop();
+    }
+
+    /// Whether `b_k` should move in front of `b_{k-1}`, `delta·|b*_{k-1}|^2 > |b*_k|^2 + mu^2·|b*_{k-1}|^2`
+    pub(crate) fn lovasz_fails(&self, k: usize, delta: &T) -> bool {
+        let mu: T = self.mu[k][k - 1].clone();
+        let previous: T = self.r[k - 1][k - 1].clone();
+        delta.clone() * previous.clone() > self.r[k][k].clone() + mu.clone() * mu * previous
+    }
+
+    /// First position `i < k` with `delta·|b*_i|^2 > |pi_i(b_k)|^2` among `i < depth` and `i >= k - depth`,
+    /// where `pi_i` projects orthogonally to `b_0..b_{i-1}`, position `k - 1` is the Lovász condition
+    pub(crate) fn deep_insertion_position(&self, k: usize, delta: &T, depth: usize) -> Option<usize> {
+        let mut projected: T = T::from_int(&self.gram[k][k], self.precision);
+        for i in 0..k {
+            if (i < depth || i + depth >= k) && delta.clone() * self.r[i][i].clone() > projected {
+                return Some(i);
+            }
+            projected = projected - self.mu[k][i].clone() * self.r[k][i].clone();
+        }
+        None
+    }
+}
+
+pub(crate) fn abs<T: GsoNumber>(value: &T) -> T {
+    if *value < T::from_int(&BigInt::zero(), 0) { -value.clone() } else { value.clone() }
+}
+
+pub(crate) fn dot(a: &[BigInt], b: &[BigInt]) -> BigInt {
+    a.iter().zip(b.iter()).fold(BigInt::zero(), |sum, (x, y)| sum + x * y)
+}
+
+fn sub_multiple(rows: &mut [Vec<BigInt>], target: usize, source: usize, x: &BigInt) {
+    let (target, source) = if target < source {
+        let (upper, lower) = rows.split_at_mut(source);
+        (&mut upper[target], &lower[0])
+    } else {
+        let (upper, lower) = rows.split_at_mut(target);
+        (&mut lower[0], &upper[source])
+    };
+    for (a, b) in target.iter_mut().zip(source.iter()) {
+        *a -= x * b;
+    }
+}
+
+/// The LLL loop shared by every number type, `b_0..b_{k-1}` stay LLL reduced with positive Gram-Schmidt norms
+/// and vectors that size reduce to zero are removed as linear dependencies. With a `depth` the Lovász swap is
+/// replaced by the deep insertion of Schnorr and Euchner, which moves `b_k` to the first allowed position where
+/// its projection is shorter than `delta` times the Gram-Schmidt vector
+pub(crate) fn lll_reduce<T: GsoNumber>(state: &mut GsoState<T>, delta: &T, eta: &T, depth: Option<usize>, max_iterations: Option<u64>) -> Result<(), GsoFailure> {
+    let mut k: usize = 0;
+    let mut iterations: u64 = 0;
+    while k < state.len() {
+        iterations += 1;
+        if let Some(limit) = max_iterations {
+            if iterations > limit {
+                return Err(GsoFailure::IterationLimit);
+            }
+        }
+        state.size_reduce(k, eta)?;
+        if state.gram[k][k].is_zero() {
+            state.remove(k);
+            continue;
+        }
+        match depth {
+            Some(depth) => {
+                if let Some(position) = state.deep_insertion_position(k, delta, depth) {
+                    state.move_row(k, position);
+                    k = position;
+                    continue;
+                }
+            }
+            None => {
+                if k > 0 && state.lovasz_fails(k, delta) {
+                    state.swap(k);
+                    k -= 1;
+                    continue;
+                }
+            }
+        }
+        k += 1;
+    }
+    Ok(())
+}
+
+/// Gram-Schmidt coefficients in `f64` with the squared norms stored as `b_i / 2^scale`, so that the profile
+/// stays usable for enumeration when the entries exceed the double range
+pub(crate) struct FloatGso {
+    pub(crate) mu: Vec<Vec<f64>>,
+    pub(crate) b: Vec<f64>,
+    pub(crate) scale: i64,
+}
+
+impl FloatGso {
+    /// `ln |b*_i|^2` without the scaling
+    pub(crate) fn ln_norm_sq(&self, i: usize) -> f64 {
+        self.b[i].ln() + self.scale as f64 * std::f64::consts::LN_2
+    }
+}
+
+/// Natural logarithm of a positive integer of any size
+pub(crate) fn ln_int(value: &BigInt) -> f64 {
+    let shift: u64 = value.bits().saturating_sub(64);
+    (value >> shift as usize).to_f64().expect("At most 64 bits").ln() + shift as f64 * std::f64::consts::LN_2
+}
+
+/// Gram-Schmidt data of linearly independent rows, computed in `f64` when the values fit and in multiprecision
+/// floats scaled by the first norm otherwise
+pub(crate) fn float_gso(rows: &[Vec<BigInt>]) -> FloatGso {
+    let size: usize = rows.len();
+    let mut state: GsoState<f64> = GsoState::resume((rows.to_vec(), Vec::new(), Vec::new()), 53);
+    if (0..size).all(|k| state.compute_row(k).is_ok() && state.r[k][k] > 0.0 && state.r[k][k].is_finite()) {
+        let b: Vec<f64> = (0..size).map(|k| state.r[k][k]).collect();
+        return FloatGso { mu: state.mu, b, scale: 0 };
+    }
+    let precision: u32 = 2 * size as u32 + 128;
+    let mut state: GsoState<BigFloat> = GsoState::resume((rows.to_vec(), Vec::new(), Vec::new()), precision);
+    for k in 0..size {
+        state.compute_row(k).expect("Multiprecision floats stay finite");
+    }
+    let scale: i64 = state.gram[0][0].bits() as i64;
+    let factor: BigFloat = BigFloat::from_fraction(&BigFraction::new(BigInt::one(), BigInt::one() << scale as usize).expect("Nonzero denominator"), precision);
+    let b: Vec<f64> = (0..size).map(|k| (state.r[k][k].clone() * factor.clone()).to_f64()).collect();
+    let mu: Vec<Vec<f64>> = state.mu.iter().map(|row| row.iter().map(|value| value.to_f64()).collect()).collect();
+    FloatGso { mu, b, scale }
+}
+
+/// Gram-Schmidt coefficients `mu` and squared norms `b` of linearly independent rows
+pub(crate) fn exact_gso(basis: &BigMatrix) -> Result<(Vec<Vec<BigFraction>>, Vec<BigFraction>), String> {
+    let ldl: LDLDecomposition = basis.gram_matrix().ldl_decomposition()
+        .map_err(|_| "The basis rows must be linearly independent".to_string())?;
+    let l: &BigMatrix = ldl.get_l();
+    let mu: Vec<Vec<BigFraction>> = (0..basis.row_count()).map(|i| l.row_view(i).expect("Row in range").to_vec()).collect();
+    Ok((mu, ldl.get_d().to_vec()))
+}
+
+/// Integral Gram-Schmidt data `d_i = det(Gram(b_0..b_{i-1}))` and `lambda_ij = d_{j+1}·mu_ij`, computed with exact
+/// divisions only, `None` when the rows are linearly dependent
+pub(crate) fn integral_gso(rows: &[Vec<BigInt>]) -> Option<(Vec<BigInt>, Vec<Vec<BigInt>>)> {
+    let mut d: Vec<BigInt> = vec![BigInt::one()];
+    let mut lambda: Vec<Vec<BigInt>> = Vec::with_capacity(rows.len());
+    for k in 0..rows.len() {
+        let mut lambda_k: Vec<BigInt> = Vec::with_capacity(k);
+        for j in 0..=k {
+            let mut u: BigInt = dot(&rows[k], &rows[j]);
+            for i in 0..j {
+                let lambda_ji: &BigInt = if j < k { &lambda[j][i] } else { &lambda_k[i] };
+                u = (&d[i + 1] * u - &lambda_k[i] * lambda_ji) / &d[i];
+            }
+            if j < k {
+                lambda_k.push(u);
+            } else if u.is_positive() {
+                d.push(u);
+            } else {
+                return None;
+            }
+        }
+        lambda.push(lambda_k);
+    }
+    Some((d, lambda))
+}
+
+/// Exact check that the rows are linearly independent, size reduced with `|mu_ij| <= eta` and satisfy the Lovász
+/// condition for `delta`
+pub(crate) fn is_lll_reduced(rows: &[Vec<BigInt>], delta: &BigFraction, eta: &BigFraction) -> bool {
+    let (d, lambda) = match integral_gso(rows) {
+        Some(gso) => gso,
+        None => return false,
+    };
+    let (eta_p, eta_q) = (eta.get_numerator(), eta.get_denominator());
+    let (delta_p, delta_q) = (delta.get_numerator(), delta.get_denominator());
+    (0..rows.len()).all(|k| {
+        let size_reduced: bool = (0..k).all(|j| &eta_q * lambda[k][j].abs() <= &eta_p * &d[j + 1]);
+        let lovasz: bool = k == 0 || &delta_p * &d[k] * &d[k] <= &delta_q * (&d[k + 1] * &d[k - 1] + &lambda[k][k - 1] * &lambda[k][k - 1]);
+        size_reduced && lovasz
+    })
+}
@@ -0,0 +1,120 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::lattice::gso::{self, GsoParts, GsoState};
+use num_bigint::BigInt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LLLParams {
+    delta: BigFraction,
+    eta: BigFraction,
+}
+
+impl LLLParams {
+    pub fn new() -> LLLParams {
+        LLLParams {
+            delta: BigFraction::new(BigInt::from(99), BigInt::from(100)).expect("Nonzero denominator"),
+            eta: BigFraction::new(BigInt::from(51), BigInt::from(100)).expect("Nonzero denominator"),
+        }
+    }
+
+    /// Lovász parameter in `(1/4, 1]`, larger values give shorter bases at a higher cost
+    pub fn with_delta(mut self, delta: BigFraction) -> LLLParams {
+        self.delta = delta;
+        self
+    }
+
+    /// Size reduction bound `|mu_ij| <= eta` in `[1/2, sqrt(delta))` for floating point reductions, which
+    /// need some slack above 1/2, exact reduction always reaches 1/2
+    pub fn with_eta(mut self, eta: BigFraction) -> LLLParams {
+        self.eta = eta;
+        self
+    }
+
+    pub fn get_delta(&self) -> &BigFraction {
+        &self.delta
+    }
+
+    pub fn get_eta(&self) -> &BigFraction {
+        &self.eta
+    }
+
+    /// Checks `delta` alone, for the exact reductions which always size reduce to 1/2 and ignore `eta`
+    pub(crate) fn validate_delta(&self) -> Result<(), String> {
+        let quarter: BigFraction = BigFraction::new(BigInt::from(1), BigInt::from(4)).expect("Nonzero denominator");
+        if self.delta <= quarter || self.delta > BigFraction::get_one() {
+            return Err(format!("delta must lie in (1/4, 1], got {}", self.delta.to_string()));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.validate_delta()?;
+        if self.eta < BigFraction::get_half() || self.eta.clone() * self.eta.clone() >= self.delta {
+            return Err(format!("eta must lie in [1/2, sqrt(delta)), got {}", self.eta.to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl Default for LLLParams {
+    fn default() -> Self {
+        LLLParams::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LLLResult {
+    reduced_basis: BigMatrix,
+    transformations: BigMatrix,
+    rank: usize,
+}
+
+impl LLLResult {
+    pub(crate) fn new(reduced_basis: BigMatrix, transformations: BigMatrix, rank: usize) -> LLLResult {
+        LLLResult { reduced_basis, transformations, rank }
+    }
+
+    /// The reduced basis in rows, the zero vectors of a dependent input are removed
+    pub fn get_reduced_basis(&self) -> &BigMatrix {
+        &self.reduced_basis
+    }
+
+    /// Unimodular `U` with `U·B` equal to the reduced basis followed by zero rows, one per dependency
+    pub fn get_transformations(&self) -> &BigMatrix {
+        &self.transformations
+    }
+
+    pub fn get_rank(&self) -> usize {
+        self.rank
+    }
+
+    pub fn get_num_dependent_vectors(&self) -> usize {
+        self.transformations.row_count() - self.rank
+    }
+
+    /// The last rows of the transformation, a basis of the integer relations `x` with `x·B = 0`
+    pub fn get_relations(&self) -> BigMatrix {
+        let count: usize = self.get_num_dependent_vectors();
+        self.transformations.submatrix(self.rank, 0, count, self.transformations.col_count()).expect("Rows in range")
+    }
+}
+
+/// LLL reduction of the lattice spanned by the rows of `basis` in exact rational arithmetic
+pub fn lll(basis: &BigMatrix, params: &LLLParams) -> Result<LLLResult, String> {
+    params.validate_delta()?;
+    let (denominator, scaled) = basis.common_denominator();
+    let mut state: GsoState<BigFraction> = GsoState::new(scaled.to_integer_rows(), 0);
+    gso::lll_reduce(&mut state, &params.delta, &BigFraction::get_half(), None, None).expect("Exact arithmetic does not fail");
+    Ok(to_result(&state.into_parts(), &denominator, basis.col_count()))
+}
+
+/// Scales the integer basis back down by `denominator`, the transformation lists the basis rows first followed
+/// by the relations, which makes it unimodular
+pub(crate) fn to_result(parts: &GsoParts, denominator: &BigInt, col_count: usize) -> LLLResult {
+    let (basis, transform, relations) = parts;
+    let rows: Vec<Vec<BigFraction>> = basis.iter()
+        .map(|row| row.iter().map(|x| BigFraction::new(x.clone(), denominator.clone()).expect("Nonzero denominator")).collect())
+        .collect();
+    let transformations: Vec<Vec<BigInt>> = transform.iter().chain(relations.iter()).cloned().collect();
+    LLLResult::new(BigMatrix::from_row_vecs(rows, col_count), BigMatrix::from_integer_rows(&transformations, transformations.len()), basis.len())
+}
@@ -0,0 +1,67 @@
+use crate::math::big_float::BigFloat;
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::decomposition::hermite_normal_form::identity_rows;
+use crate::math::lattice::gso::{self, GsoNumber, GsoParts, GsoState};
+use crate::math::lattice::lll::{self, LLLParams, LLLResult};
+use num_bigint::BigInt;
+
+// mantissa bits of an f64
+const DOUBLE_PRECISION: u32 = 53;
+
+/// LLL reduction with floating point Gram-Schmidt coefficients computed from the exact integer Gram matrix.
+/// Starts in `f64` and moves to `BigFloat` with doubling precision whenever rounding errors show up, that is
+/// non finite values, a size reduction that does not converge, too many iterations or a result failing the
+/// exact check, and finishes in exact arithmetic as a last resort. The returned basis is always verified exactly
+pub fn lll_fp(basis: &BigMatrix, params: &LLLParams) -> Result<LLLResult, String> {
+    params.validate()?;
+    let (denominator, scaled) = basis.common_denominator();
+    let rows: Vec<Vec<BigInt>> = scaled.to_integer_rows();
+    let size: usize = rows.len();
+    let parts: GsoParts = reduce((rows, identity_rows(size), Vec::new()), params, None, true);
+    Ok(lll::to_result(&parts, &denominator, basis.col_count()))
+}
+
+/// Floating point reduction of an integer state with the precision ladder of `lll_fp`, with deep insertions up
+/// to `depth` when given. Without `verify` a run that finishes without precision trouble is accepted as is
+pub(crate) fn reduce(mut parts: GsoParts, params: &LLLParams, depth: Option<usize>, verify: bool) -> GsoParts {
+    let max_iterations: u64 = iteration_limit(&parts.0);
+    // the L² analysis needs about 1.6 bits per dimension
+    let max_precision: u32 = 2 * parts.0.len() as u32 + 128;
+    let mut precision: u32 = DOUBLE_PRECISION;
+    while precision <= max_precision {
+        let (next, reduced) = if precision == DOUBLE_PRECISION {
+            attempt::<f64>(parts, params, precision, depth, max_iterations, verify)
+        } else {
+            attempt::<BigFloat>(parts, params, precision, depth, max_iterations, verify)
+        };
+        parts = next;
+        if reduced {
+            return parts;
+        }
+        precision *= 2;
+    }
+    let mut state: GsoState<BigFraction> = GsoState::resume(parts, 0);
+    gso::lll_reduce(&mut state, params.get_delta(), &BigFraction::get_half(), depth, None).expect("Exact arithmetic does not fail");
+    state.into_parts()
+}
+
+/// Runs the reduction in `T` from the given state, the bounds are tightened halfway to the ideal values so that
+/// a run without precision trouble passes the exact check for the requested `delta` and `eta`
+fn attempt<T: GsoNumber>(parts: GsoParts, params: &LLLParams, precision: u32, depth: Option<usize>, max_iterations: u64, verify: bool) -> (GsoParts, bool) {
+    let half: BigFraction = BigFraction::get_half();
+    let delta: T = T::from_fraction(&params.get_delta().clone().add(BigFraction::get_one()).mul(half.clone()), precision);
+    let eta: T = T::from_fraction(&params.get_eta().clone().add(half.clone()).mul(half), precision);
+    let mut state: GsoState<T> = GsoState::resume(parts, precision);
+    let outcome = gso::lll_reduce(&mut state, &delta, &eta, depth, Some(max_iterations));
+    let parts: GsoParts = state.into_parts();
+    let reduced: bool = outcome.is_ok() && (!verify || gso::is_lll_reduced(&parts.0, params.get_delta(), params.get_eta()));
+    (parts, reduced)
+}
+
+/// Generous bound on the loop iterations, LLL performs `O(n^2·log B)` swaps
+fn iteration_limit(rows: &[Vec<BigInt>]) -> u64 {
+    let bits: u64 = rows.iter().flatten().map(|x| x.bits()).max().unwrap_or(0);
+    let size: u64 = rows.len() as u64 + 1;
+    16 * size * size * (bits + 16)
+}
@@ -0,0 +1,23 @@
+use crate::math::big_matrix::BigMatrix;
+use crate::math::decomposition::hermite_normal_form::identity_rows;
+use crate::math::lattice::gso::GsoParts;
+use crate::math::lattice::lll::{self, LLLParams, LLLResult};
+use crate::math::lattice::lll_fp;
+use num_bigint::BigInt;
+
+/// MLLL of Pohst on a generating set: the rows of `generators` may be linearly dependent, every vector that
+/// size reduces to zero is dropped and its transformation row recorded as a relation. Returns an LLL reduced
+/// basis of the generated lattice with its rank, and the relations are LLL reduced in turn so that they form a
+/// short basis of the integer relations between the generators
+pub fn mlll(generators: &BigMatrix, params: &LLLParams) -> Result<LLLResult, String> {
+    params.validate()?;
+    let (denominator, scaled) = generators.common_denominator();
+    let rows: Vec<Vec<BigInt>> = scaled.to_integer_rows();
+    let size: usize = rows.len();
+    let (basis, transform, relations) = lll_fp::reduce((rows, identity_rows(size), Vec::new()), params, None, true);
+    // the relations are independent rows of a unimodular matrix, reducing them keeps it unimodular
+    let count: usize = relations.len();
+    let (relations, _, _) = lll_fp::reduce((relations, identity_rows(count), Vec::new()), params, None, true);
+    let parts: GsoParts = (basis, transform, relations);
+    Ok(lll::to_result(&parts, &denominator, generators.col_count()))
+}
@@ -0,0 +1,13 @@
+pub mod bkz;
+pub mod construct;
+pub mod cvp;
+pub mod deep_lll;
+pub mod diagnostics;
+mod enumeration;
+mod gso;
+pub mod lll;
+pub mod lll_fp;
+pub mod mlll;
+pub mod polytope;
+mod simplex;
+pub mod svp;
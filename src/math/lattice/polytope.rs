@@ -0,0 +1,210 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::big_vector::BigVector;
+use crate::math::lattice::lll::LLLParams;
+use crate::math::lattice::lll_fp::lll_fp;
+use crate::math::lattice::simplex::{self, LpOutcome};
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+/// Region `normal_i·x <= bound_i` of the ambient space
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constraints {
+    dimension: usize,
+    normals: Vec<BigVector>,
+    bounds: Vec<BigFraction>,
+}
+
+impl Constraints {
+    /// The whole space, bounded regions need inequalities
+    pub fn new(dimension: usize) -> Constraints {
+        Constraints { dimension, normals: Vec::new(), bounds: Vec::new() }
+    }
+
+    /// The box `lower_i <= x_i <= upper_i`
+    pub fn from_box(lower: &BigVector, upper: &BigVector) -> Result<Constraints, String> {
+        if lower.dimension() != upper.dimension() {
+            return Err(format!("The bounds have dimensions {} and {}", lower.dimension(), upper.dimension()));
+        }
+        let dimension: usize = lower.dimension();
+        let mut constraints: Constraints = Constraints::new(dimension);
+        for (i, (low, high)) in lower.iter().zip(upper.iter()).enumerate() {
+            let unit: BigVector = BigVector::basis(dimension, i)?;
+            constraints.add_inequality(unit.map(|x| -x.clone()), -low.clone())?;
+            constraints.add_inequality(unit, high.clone())?;
+        }
+        Ok(constraints)
+    }
+
+    /// Adds `normal·x <= bound`
+    pub fn add_inequality(&mut self, normal: BigVector, bound: BigFraction) -> Result<(), String> {
+        if normal.dimension() != self.dimension {
+            return Err(format!("The normal has dimension {} but the region has dimension {}", normal.dimension(), self.dimension));
+        }
+        self.normals.push(normal);
+        self.bounds.push(bound);
+        Ok(())
+    }
+
+    pub fn get_dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn contains(&self, point: &BigVector) -> bool {
+        self.normals.iter().zip(self.bounds.iter()).all(|(normal, bound)| match normal.dot(point) {
+            Ok(value) => value <= *bound,
+            Err(_) => false,
+        })
+    }
+}
+
+/// Lazy depth first enumeration of the lattice points of a bounded region, see `enumerate_points`
+#[derive(Debug, Clone)]
+pub struct PointEnumerator {
+    dimension: usize,
+    basis: Vec<BigVector>,
+    /// `rows[i][j]` is `normal_i·b_j`, the constraints on the coefficients in the reduced basis
+    rows: Vec<Vec<BigFraction>>,
+    bounds: Vec<BigFraction>,
+    /// Current value and last value of each fixed coefficient
+    levels: Vec<(BigInt, BigInt)>,
+    done: bool,
+}
+
+/// Iterates over every lattice vector of the rows of `basis` inside `constraints`, exactly in `BigFraction`.
+/// The basis is LLL reduced first and the coefficients are fixed one at a time, each within the range left by
+/// the exact linear programming relaxation given the coefficients fixed so far. The region must be bounded
+/// within the span of the lattice, an unbounded one is an error
+pub fn enumerate_points(basis: &BigMatrix, constraints: &Constraints) -> Result<PointEnumerator, String> {
+    if basis.col_count() != constraints.dimension {
+        return Err(format!("The basis has dimension {} but the region has dimension {}", basis.col_count(), constraints.dimension));
+    }
+    let reduced: Vec<BigVector> = lll_fp(basis, &LLLParams::default())?.get_reduced_basis().rows();
+    let rows: Vec<Vec<BigFraction>> = constraints.normals.iter()
+        .map(|normal| reduced.iter().map(|row| normal.dot(row).expect("Dimensions match")).collect())
+        .collect();
+    let mut enumerator: PointEnumerator = PointEnumerator {
+        dimension: constraints.dimension,
+        basis: reduced,
+        rows,
+        bounds: constraints.bounds.clone(),
+        levels: Vec::new(),
+        done: false,
+    };
+    if enumerator.basis.is_empty() {
+        enumerator.done = !constraints.contains(&BigVector::zeros(constraints.dimension));
+        return Ok(enumerator);
+    }
+    for j in 0..enumerator.basis.len() {
+        for sign in [BigFraction::get_one(), BigFraction::get_minus_one()].iter() {
+            let mut objective: Vec<BigFraction> = vec![BigFraction::get_zero(); enumerator.basis.len()];
+            objective[j] = sign.clone();
+            match simplex::maximize(&enumerator.rows, &enumerator.bounds, &objective) {
+                LpOutcome::Optimal(_) => {}
+                LpOutcome::Unbounded => return Err("The region is unbounded within the span of the lattice".to_string()),
+                LpOutcome::Infeasible => {
+                    enumerator.done = true;
+                    return Ok(enumerator);
+                }
+            }
+        }
+    }
+    Ok(enumerator)
+}
+
+impl PointEnumerator {
+    /// Integer range of the coefficient at `depth` given the fixed ones, `None` when the slice is empty
+    fn range(&self, depth: usize) -> Option<(BigInt, BigInt)> {
+        let rhs: Vec<BigFraction> = self.rows.iter().zip(self.bounds.iter())
+            .map(|(row, bound)| (0..depth).fold(bound.clone(), |rhs, j| rhs - row[j].clone() * BigFraction::from(self.levels[j].0.clone())))
+            .collect();
+        let (low, high) = if depth + 1 == self.basis.len() {
+            single_range(&self.rows, &rhs, depth)?
+        } else {
+            let rows: Vec<Vec<BigFraction>> = self.rows.iter().map(|row| row[depth..].to_vec()).collect();
+            let mut objective: Vec<BigFraction> = vec![BigFraction::get_zero(); self.basis.len() - depth];
+            objective[0] = BigFraction::get_one();
+            let high: BigFraction = optimum(simplex::maximize(&rows, &rhs, &objective))?;
+            objective[0] = BigFraction::get_minus_one();
+            let low: BigFraction = -optimum(simplex::maximize(&rows, &rhs, &objective))?;
+            (low, high)
+        };
+        let (low, high) = (low.ceil(), high.floor());
+        if low <= high { Some((low, high)) } else { None }
+    }
+
+    /// Moves to the next value of the deepest coefficient that has one left
+    fn advance(&mut self) {
+        while let Some((value, last)) = self.levels.last_mut() {
+            if value < last {
+                *value += 1;
+                return;
+            }
+            self.levels.pop();
+        }
+        self.done = true;
+    }
+
+    fn point(&self) -> BigVector {
+        self.basis.iter().zip(self.levels.iter()).fold(BigVector::zeros(self.dimension), |point, (row, (value, _))| {
+            let scale: BigFraction = BigFraction::from(value.clone());
+            point.zip_with(row, |a, b| a.clone() + scale.clone() * b.clone()).expect("Dimensions match")
+        })
+    }
+}
+
+impl Iterator for PointEnumerator {
+    type Item = BigVector;
+
+    fn next(&mut self) -> Option<BigVector> {
+        while !self.done {
+            let depth: usize = self.levels.len();
+            if depth == self.basis.len() {
+                let point: BigVector = self.point();
+                self.advance();
+                return Some(point);
+            }
+            match self.range(depth) {
+                Some(range) => self.levels.push(range),
+                None => self.advance(),
+            }
+        }
+        None
+    }
+}
+
+fn optimum(outcome: LpOutcome) -> Option<BigFraction> {
+    match outcome {
+        LpOutcome::Optimal(value) => Some(value),
+        LpOutcome::Infeasible => None,
+        LpOutcome::Unbounded => unreachable!("Slices of a bounded region are bounded"),
+    }
+}
+
+/// Range of the last free coefficient read off the constraints directly
+fn single_range(rows: &[Vec<BigFraction>], rhs: &[BigFraction], column: usize) -> Option<(BigFraction, BigFraction)> {
+    let mut low: Option<BigFraction> = None;
+    let mut high: Option<BigFraction> = None;
+    for (row, bound) in rows.iter().zip(rhs.iter()) {
+        let coefficient: &BigFraction = &row[column];
+        if coefficient.is_zero() {
+            if *bound < BigFraction::get_zero() {
+                return None;
+            }
+            continue;
+        }
+        let limit: BigFraction = bound.clone() / coefficient.clone();
+        if *coefficient > BigFraction::get_zero() {
+            high = Some(match high {
+                Some(high) if high <= limit => high,
+                _ => limit,
+            });
+        } else {
+            low = Some(match low {
+                Some(low) if low >= limit => low,
+                _ => limit,
+            });
+        }
+    }
+    Some((low.expect("Bounded region"), high.expect("Bounded region")))
+}
@@ -0,0 +1,166 @@
+use crate::math::big_fraction::BigFraction;
+
+/// Outcome of a linear program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LpOutcome {
+    Optimal(BigFraction),
+    Unbounded,
+    Infeasible,
+}
+
+/// Maximizes `objective·y` over the free variables `y` subject to `rows·y <= rhs` with the exact two phase
+/// simplex method, Bland's rule rules out cycling
+pub(crate) fn maximize(rows: &[Vec<BigFraction>], rhs: &[BigFraction], objective: &[BigFraction]) -> LpOutcome {
+    let variables: usize = objective.len();
+    let (mut tableau, artificial) = Tableau::new(rows, rhs, variables);
+    // phase one drives the artificial variables to zero
+    let mut cost: Vec<BigFraction> = vec![BigFraction::get_zero(); tableau.width + 1];
+    for column in artificial.iter() {
+        cost[*column] = -BigFraction::get_one();
+    }
+    tableau.canonicalize(&mut cost);
+    tableau.optimize(&mut cost, tableau.width);
+    if cost[tableau.width] != BigFraction::get_zero() {
+        return LpOutcome::Infeasible;
+    }
+    let first_artificial: usize = tableau.width - artificial.len();
+    tableau.remove_artificial(first_artificial);
+    let mut cost: Vec<BigFraction> = vec![BigFraction::get_zero(); tableau.width + 1];
+    for (i, value) in objective.iter().enumerate() {
+        cost[i] = value.clone();
+        cost[variables + i] = -value.clone();
+    }
+    tableau.canonicalize(&mut cost);
+    if tableau.optimize(&mut cost, first_artificial) {
+        LpOutcome::Optimal(-cost[tableau.width].clone())
+    } else {
+        LpOutcome::Unbounded
+    }
+}
+
+/// Equality form `[y+, y-, slack, artificial]·x = rhs` with `x >= 0` and `rhs >= 0`, the last entry of each row
+/// is its right hand side and `basic[i]` is the variable solved for in row `i`
+struct Tableau {
+    rows: Vec<Vec<BigFraction>>,
+    basic: Vec<usize>,
+    width: usize,
+}
+
+impl Tableau {
+    fn new(rows: &[Vec<BigFraction>], rhs: &[BigFraction], variables: usize) -> (Tableau, Vec<usize>) {
+        let count: usize = rows.len();
+        let negative: Vec<usize> = (0..count).filter(|&i| rhs[i] < BigFraction::get_zero()).collect();
+        let width: usize = 2 * variables + count + negative.len();
+        let mut tableau: Tableau = Tableau { rows: Vec::with_capacity(count), basic: Vec::with_capacity(count), width };
+        let mut artificial: Vec<usize> = Vec::with_capacity(negative.len());
+        for (i, row) in rows.iter().enumerate() {
+            let mut entries: Vec<BigFraction> = vec![BigFraction::get_zero(); width + 1];
+            for (j, value) in row.iter().enumerate() {
+                entries[j] = value.clone();
+                entries[variables + j] = -value.clone();
+            }
+            entries[2 * variables + i] = BigFraction::get_one();
+            entries[width] = rhs[i].clone();
+            if rhs[i] < BigFraction::get_zero() {
+                for value in entries.iter_mut() {
+                    *value = -value.clone();
+                }
+                let column: usize = 2 * variables + count + artificial.len();
+                entries[column] = BigFraction::get_one();
+                artificial.push(column);
+                tableau.basic.push(column);
+            } else {
+                tableau.basic.push(2 * variables + i);
+            }
+            tableau.rows.push(entries);
+        }
+        (tableau, artificial)
+    }
+
+    /// Rewrites the objective `cost` in terms of the nonbasic variables, the last entry holds minus its value
+    fn canonicalize(&self, cost: &mut [BigFraction]) {
+        for (row, &basic) in self.rows.iter().zip(self.basic.iter()) {
+            let factor: BigFraction = cost[basic].clone();
+            if factor != BigFraction::get_zero() {
+                for (value, entry) in cost.iter_mut().zip(row.iter()) {
+                    *value = value.clone() - factor.clone() * entry.clone();
+                }
+            }
+        }
+    }
+
+    fn pivot(&mut self, cost: &mut [BigFraction], row: usize, column: usize) {
+        let pivot: BigFraction = self.rows[row][column].clone();
+        for value in self.rows[row].iter_mut() {
+            *value = value.clone() / pivot.clone();
+        }
+        let pivot_row: Vec<BigFraction> = self.rows[row].clone();
+        for (i, entries) in self.rows.iter_mut().enumerate() {
+            let factor: BigFraction = entries[column].clone();
+            if i != row && factor != BigFraction::get_zero() {
+                for (value, entry) in entries.iter_mut().zip(pivot_row.iter()) {
+                    *value = value.clone() - factor.clone() * entry.clone();
+                }
+            }
+        }
+        let factor: BigFraction = cost[column].clone();
+        for (value, entry) in cost.iter_mut().zip(pivot_row.iter()) {
+            *value = value.clone() - factor.clone() * entry.clone();
+        }
+        self.basic[row] = column;
+    }
+
+    /// Pivots until no column below `allowed` improves the objective, `false` when it is unbounded
+    fn optimize(&mut self, cost: &mut [BigFraction], allowed: usize) -> bool {
+        let zero: BigFraction = BigFraction::get_zero();
+        while let Some(column) = (0..allowed).find(|&j| cost[j] > zero) {
+            let mut leaving: Option<(usize, BigFraction)> = None;
+            for (i, entries) in self.rows.iter().enumerate() {
+                if entries[column] <= zero {
+                    continue;
+                }
+                let ratio: BigFraction = entries[self.width].clone() / entries[column].clone();
+                let better: bool = match &leaving {
+                    Some((best, best_ratio)) => ratio < *best_ratio || (ratio == *best_ratio && self.basic[i] < self.basic[*best]),
+                    None => true,
+                };
+                if better {
+                    leaving = Some((i, ratio));
+                }
+            }
+            match leaving {
+                Some((row, _)) => self.pivot(cost, row, column),
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Pivots the artificial variables left at zero out of the basis and drops their columns, rows that only
+    /// involve artificial variables are redundant and removed
+    fn remove_artificial(&mut self, first_artificial: usize) {
+        let mut row: usize = 0;
+        while row < self.rows.len() {
+            if self.basic[row] >= first_artificial {
+                match (0..first_artificial).find(|&j| self.rows[row][j] != BigFraction::get_zero()) {
+                    Some(column) => {
+                        let mut unused: Vec<BigFraction> = vec![BigFraction::get_zero(); self.width + 1];
+                        self.pivot(&mut unused, row, column);
+                    }
+                    None => {
+                        self.rows.remove(row);
+                        self.basic.remove(row);
+                        continue;
+                    }
+                }
+            }
+            row += 1;
+        }
+        for entries in self.rows.iter_mut() {
+            let rhs: BigFraction = entries[self.width].clone();
+            entries.truncate(first_artificial);
+            entries.push(rhs);
+        }
+        self.width = first_artificial;
+    }
+}
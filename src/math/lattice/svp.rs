@@ -0,0 +1,104 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::big_vector::BigVector;
+use crate::math::lattice::enumeration::{Enumeration, EnumerationStats};
+use crate::math::lattice::gso;
+use num_bigint::BigInt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SVPParams {
+    shrink_radius: bool,
+    node_limit: Option<u64>,
+}
+
+impl SVPParams {
+    pub fn new() -> SVPParams {
+        SVPParams { shrink_radius: true, node_limit: None }
+    }
+
+    /// Whether the radius shrinks to every shorter vector found, which prunes the search. Keeping it fixed
+    /// walks every vector of the ball, which is only useful together with a node limit as a cost estimate
+    pub fn with_shrink_radius(mut self, shrink_radius: bool) -> SVPParams {
+        self.shrink_radius = shrink_radius;
+        self
+    }
+
+    /// Maximum number of enumeration nodes, a search cut short reports the best vector found so far
+    pub fn with_node_limit(mut self, node_limit: Option<u64>) -> SVPParams {
+        self.node_limit = node_limit;
+        self
+    }
+}
+
+impl Default for SVPParams {
+    fn default() -> Self {
+        SVPParams::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SVPResult {
+    solution: Option<(BigVector, BigVector, BigFraction)>,
+    nodes: u64,
+    complete: bool,
+}
+
+impl SVPResult {
+    /// The shortest nonzero lattice vector within the radius, up to sign
+    pub fn get_vector(&self) -> Option<&BigVector> {
+        self.solution.as_ref().map(|(vector, _, _)| vector)
+    }
+
+    /// Integer coefficients of the vector in the rows of the basis
+    pub fn get_coefficients(&self) -> Option<&BigVector> {
+        self.solution.as_ref().map(|(_, coefficients, _)| coefficients)
+    }
+
+    pub fn get_norm_sq(&self) -> Option<&BigFraction> {
+        self.solution.as_ref().map(|(_, _, norm_sq)| norm_sq)
+    }
+
+    pub fn get_nodes(&self) -> u64 {
+        self.nodes
+    }
+
+    /// `false` when the node limit stopped the search, a complete search without a vector proves that the
+    /// lattice has no nonzero vector within the radius
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+}
+
+/// Shortest nonzero vector of the lattice spanned by the linearly independent rows of `basis` with squared norm
+/// at most `radius_sq`, by default the smallest squared row norm. Schnorr-Euchner enumeration runs on the exact
+/// Gram-Schmidt data so a complete search is a proof, it is much faster on an LLL or BKZ reduced basis
+pub fn svp_enumerate(basis: &BigMatrix, radius_sq: Option<&BigFraction>, params: &SVPParams) -> Result<SVPResult, String> {
+    let size: usize = basis.row_count();
+    if size == 0 {
+        return Ok(SVPResult { solution: None, nodes: 0, complete: true });
+    }
+    let (mu, b) = gso::exact_gso(basis)?;
+    let radius: BigFraction = match radius_sq {
+        Some(radius) if *radius < BigFraction::get_zero() => return Err(format!("The radius must not be negative, got {}", radius.to_string())),
+        Some(radius) => radius.clone(),
+        None => basis.rows().iter().map(|row| row.magnitude_sq()).min().expect("Nonempty basis"),
+    };
+    let pruning: Vec<BigFraction> = vec![BigFraction::get_one(); size];
+    let mut best: Option<(Vec<BigInt>, BigFraction)> = None;
+    let stats: EnumerationStats = Enumeration::new(&mu, &b, &pruning, radius, params.node_limit).run(|coefficients, norm| {
+        let shorter: bool = match &best {
+            Some((_, best_norm)) => norm < best_norm,
+            None => true,
+        };
+        if shorter {
+            best = Some((coefficients.to_vec(), norm.clone()));
+        }
+        if params.shrink_radius && shorter { Some(norm.clone()) } else { None }
+    });
+    let solution: Option<(BigVector, BigVector, BigFraction)> = best.map(|(coefficients, norm_sq)| {
+        let coefficients: BigVector = BigVector::new_int(&coefficients);
+        let vector: BigVector = basis.transpose().multiply_vector(&coefficients).expect("Dimensions match");
+        (vector, coefficients, norm_sq)
+    });
+    Ok(SVPResult { solution, nodes: stats.nodes, complete: stats.complete })
+}
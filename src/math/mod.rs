@@ -1,2 +1,8 @@
+pub mod big_float;
 pub mod big_fraction;
-pub mod big_vector;
\ No newline at end of file
+pub mod big_matrix;
+pub mod big_vector;
+pub mod decomposition;
+pub mod lattice;
+pub mod small_vector;
+pub mod sparse_matrix;
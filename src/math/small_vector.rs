@@ -0,0 +1,295 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_vector::{self, BigVector, ParseBigVectorError, VectorFormat};
+use core::fmt;
+use num_bigint::BigInt;
+use num_integer::{ExtendedGcd, Integer};
+use num_traits::{One, Signed, Zero};
+use std::convert::TryFrom;
+use std::ops::{Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// Fixed dimension counterpart of `BigVector` stored on the stack
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct SVector<T, const N: usize> {
+    numbers: [T; N],
+}
+
+impl<T, const N: usize> SVector<T, N> {
+    pub fn new(numbers: [T; N]) -> SVector<T, N> {
+        SVector { numbers }
+    }
+
+    pub fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> SVector<T, N> {
+        let mut index: usize = 0;
+        SVector {
+            numbers: [(); N].map(|_| {
+                let value: T = f(index);
+                index += 1;
+                value
+            }),
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Result<T, String> where T: Clone {
+        match self.numbers.get(index) {
+            Some(value) => Ok(value.clone()),
+            None => Err(format!("Index {}, dimension {}", index, N)),
+        }
+    }
+
+    pub fn set(&mut self, index: usize, value: T) -> Result<T, String> where T: Clone {
+        if index >= N {
+            return Err(format!("Index {}, dimension {}", index, N));
+        }
+        self.numbers[index] = value.clone();
+        Ok(value)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.numbers.iter()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.numbers
+    }
+
+    pub fn into_inner(self) -> [T; N] {
+        self.numbers
+    }
+
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<&Self, String> {
+        if i >= N || j >= N {
+            return Err(format!("Index {} or {}, dimension {}", i, j, N));
+        }
+        self.numbers.swap(i, j);
+        Ok(self)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, mut f: F) -> SVector<U, N> {
+        SVector::from_fn(|i| f(&self.numbers[i]))
+    }
+
+    pub fn zip_with<U, F: FnMut(&T, &T) -> U>(&self, other: &SVector<T, N>, mut f: F) -> SVector<U, N> {
+        SVector::from_fn(|i| f(&self.numbers[i], &other.numbers[i]))
+    }
+
+    pub fn dimension(&self) -> usize {
+        N
+    }
+}
+
+impl<T: Clone + Zero, const N: usize> SVector<T, N> {
+    pub fn zeros() -> SVector<T, N> {
+        SVector::from_fn(|_| T::zero())
+    }
+
+    /// The unit vector `e_index`
+    pub fn basis(index: usize) -> Result<SVector<T, N>, String> where T: One {
+        if index >= N {
+            return Err(format!("Index {}, dimension {}", index, N));
+        }
+        Ok(SVector::from_fn(|i| if i == index { T::one() } else { T::zero() }))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numbers.iter().all(|x| x.is_zero())
+    }
+
+    pub fn add(&mut self, other: &SVector<T, N>) {
+        for (a, b) in self.numbers.iter_mut().zip(other.numbers.iter()) {
+            *a = a.clone() + b.clone();
+        }
+    }
+
+    pub fn sub(&mut self, other: &SVector<T, N>) where T: Sub<Output=T> {
+        for (a, b) in self.numbers.iter_mut().zip(other.numbers.iter()) {
+            *a = a.clone() - b.clone();
+        }
+    }
+
+    pub fn mul(&mut self, other: &SVector<T, N>) where T: Mul<Output=T> {
+        for (a, b) in self.numbers.iter_mut().zip(other.numbers.iter()) {
+            *a = a.clone() * b.clone();
+        }
+    }
+
+    pub fn div(&mut self, other: &SVector<T, N>) -> Result<(), String> where T: Div<Output=T> {
+        if let Some(index) = other.numbers.iter().position(|x| x.is_zero()) {
+            return Err(format!("Div impossible for {}", index));
+        }
+        for (a, b) in self.numbers.iter_mut().zip(other.numbers.iter()) {
+            *a = a.clone() / b.clone();
+        }
+        Ok(())
+    }
+
+    pub fn scale(&mut self, scalar: &T) where T: Mul<Output=T> {
+        for a in self.numbers.iter_mut() {
+            *a = a.clone() * scalar.clone();
+        }
+    }
+
+    pub fn dot(&self, other: &SVector<T, N>) -> T where T: Mul<Output=T> {
+        let mut result: T = T::zero();
+        for (a, b) in self.numbers.iter().zip(other.numbers.iter()) {
+            result = result + a.clone() * b.clone();
+        }
+        result
+    }
+
+    pub fn magnitude_sq(&self) -> T where T: Mul<Output=T> {
+        self.dot(self)
+    }
+
+    pub fn norm_l1(&self) -> T where T: PartialOrd + Neg<Output=T> {
+        let mut norm: T = T::zero();
+        for a in self.numbers.iter() {
+            norm = norm + abs(a);
+        }
+        norm
+    }
+
+    pub fn norm_linf(&self) -> T where T: PartialOrd + Neg<Output=T> {
+        let mut norm: T = T::zero();
+        for a in self.numbers.iter() {
+            let value: T = abs(a);
+            if value > norm {
+                norm = value;
+            }
+        }
+        norm
+    }
+
+    pub fn weighted_norm_sq(&self, weights: &SVector<T, N>) -> T where T: Mul<Output=T> {
+        let mut norm: T = T::zero();
+        for (value, weight) in self.numbers.iter().zip(weights.numbers.iter()) {
+            norm = norm + value.clone() * value.clone() * weight.clone();
+        }
+        norm
+    }
+
+    /// Computes `v^T G v` where the gram matrix is given by its rows
+    pub fn quadratic_form(&self, gram_matrix: &[SVector<T, N>; N]) -> T where T: Mul<Output=T> {
+        let mut result: T = T::zero();
+        for (value, row) in self.numbers.iter().zip(gram_matrix.iter()) {
+            result = result + value.clone() * row.dot(self);
+        }
+        result
+    }
+}
+
+impl<const N: usize> SVector<BigFraction, N> {
+    /// Returns `(floor(|v|), ceil(|v|))`, the euclidean norm is only rational for perfect squares
+    pub fn norm_l2(&self) -> (BigInt, BigInt) {
+        let magnitude: BigFraction = self.magnitude_sq();
+        let lower: BigInt = magnitude.clone().floor().sqrt();
+        let upper_sq: BigInt = magnitude.ceil();
+        let mut upper: BigInt = upper_sq.sqrt();
+        if &upper * &upper < upper_sq {
+            upper += BigInt::one();
+        }
+        (lower, upper)
+    }
+
+    pub fn is_integral(&self) -> bool {
+        self.numbers.iter().all(|x| x.get_denominator().is_one())
+    }
+
+    fn integral_entries(&self, modulus: &BigInt) -> Result<SVector<BigInt, N>, String> {
+        if !modulus.is_positive() {
+            return Err(format!("The modulus {} is not positive", modulus));
+        }
+        if !self.is_integral() {
+            return Err(format!("The vector {} is not integral", self));
+        }
+        Ok(self.map(|x| x.get_numerator()))
+    }
+
+    /// Representatives in `[0, m)`
+    pub fn reduce_mod(&self, modulus: &BigInt) -> Result<SVector<BigFraction, N>, String> {
+        let entries: SVector<BigInt, N> = self.integral_entries(modulus)?;
+        Ok(entries.map(|x| BigFraction::from(x.mod_floor(modulus))))
+    }
+
+    /// Representatives in `(-m/2, m/2]`
+    pub fn reduce_mod_centered(&self, modulus: &BigInt) -> Result<SVector<BigFraction, N>, String> {
+        let entries: SVector<BigInt, N> = self.integral_entries(modulus)?;
+        Ok(entries.map(|x| {
+            let value: BigInt = x.mod_floor(modulus);
+            BigFraction::from(if &value * 2 > *modulus { value - modulus } else { value })
+        }))
+    }
+
+    pub fn is_congruent(&self, other: &SVector<BigFraction, N>, modulus: &BigInt) -> Result<bool, String> {
+        let entries: SVector<BigInt, N> = self.integral_entries(modulus)?;
+        let other_entries: SVector<BigInt, N> = other.integral_entries(modulus)?;
+        Ok(entries.iter().zip(other_entries.iter()).all(|(a, b)| (a - b).mod_floor(modulus).is_zero()))
+    }
+
+    /// Multiplies by the inverse of `scalar` modulo `m`, reduced to `[0, m)`
+    pub fn mod_inverse_scale(&self, scalar: &BigInt, modulus: &BigInt) -> Result<SVector<BigFraction, N>, String> {
+        let entries: SVector<BigInt, N> = self.integral_entries(modulus)?;
+        let gcd: ExtendedGcd<BigInt> = scalar.extended_gcd(modulus);
+        if !gcd.gcd.is_one() {
+            return Err(format!("{} is not invertible modulo {}", scalar, modulus));
+        }
+        let inverse: BigInt = gcd.x.mod_floor(modulus);
+        Ok(entries.map(|x| BigFraction::from((x * &inverse).mod_floor(modulus))))
+    }
+
+    pub fn format(&self, style: VectorFormat) -> String {
+        big_vector::format_entries(self.numbers.iter(), style)
+    }
+}
+
+impl<const N: usize> fmt::Display for SVector<BigFraction, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(VectorFormat::LattiCG))
+    }
+}
+
+impl<const N: usize> FromStr for SVector<BigFraction, N> {
+    type Err = ParseBigVectorError;
+
+    /// Accepts the notations of `BigVector`, with exactly `N` entries
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers: Vec<BigFraction> = big_vector::parse_entries(s)?;
+        let numbers: [BigFraction; N] = <[BigFraction; N]>::try_from(numbers).map_err(|_| ParseBigVectorError::invalid_dimension())?;
+        Ok(SVector::new(numbers))
+    }
+}
+
+fn abs<T: Clone + Zero + PartialOrd + Neg<Output=T>>(value: &T) -> T {
+    if *value < T::zero() { -value.clone() } else { value.clone() }
+}
+
+/// Moves the entries into the single allocation of the `BigVector`, without converting or cloning them
+impl<const N: usize> From<SVector<BigFraction, N>> for BigVector {
+    fn from(vector: SVector<BigFraction, N>) -> Self {
+        BigVector::from(Vec::from(vector.numbers))
+    }
+}
+
+impl<const N: usize> From<SVector<BigInt, N>> for BigVector {
+    fn from(vector: SVector<BigInt, N>) -> Self {
+        BigVector::from(Vec::from(vector.numbers).into_iter().map(BigFraction::from).collect::<Vec<BigFraction>>())
+    }
+}
+
+impl<const N: usize> From<SVector<i64, N>> for BigVector {
+    fn from(vector: SVector<i64, N>) -> Self {
+        BigVector::from(vector.numbers.iter().map(|&x| BigFraction::from(x)).collect::<Vec<BigFraction>>())
+    }
+}
+
+impl<const N: usize> TryFrom<&BigVector> for SVector<BigFraction, N> {
+    type Error = String;
+
+    fn try_from(vector: &BigVector) -> Result<Self, Self::Error> {
+        if vector.dimension() != N {
+            return Err(format!("The vector ({}) is not the same dimension ({})", vector.dimension(), N));
+        }
+        let mut numbers = vector.iter().cloned();
+        Ok(SVector::from_fn(|_| numbers.next().expect("Dimension was checked")))
+    }
+}
@@ -0,0 +1,251 @@
+use crate::math::big_fraction::BigFraction;
+use crate::math::big_matrix::BigMatrix;
+use crate::math::big_vector::BigVector;
+use num_traits::Zero;
+
+/// Compressed sparse row storage, only the nonzero entries are kept and every row is sorted by column
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SparseMatrix {
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<BigFraction>,
+    row_count: usize,
+    col_count: usize,
+}
+
+impl SparseMatrix {
+    pub fn zeros(row_count: usize, col_count: usize) -> SparseMatrix {
+        SparseMatrix {
+            row_offsets: vec![0; row_count + 1],
+            col_indices: Vec::new(),
+            values: Vec::new(),
+            row_count,
+            col_count,
+        }
+    }
+
+    pub fn identity(size: usize) -> SparseMatrix {
+        SparseMatrix {
+            row_offsets: (0..=size).collect(),
+            col_indices: (0..size).collect(),
+            values: vec![BigFraction::get_one(); size],
+            row_count: size,
+            col_count: size,
+        }
+    }
+
+    /// Builds the matrix from `(row, col, value)` entries, duplicates are summed
+    pub fn from_triplets(row_count: usize, col_count: usize, triplets: &[(usize, usize, BigFraction)]) -> Result<SparseMatrix, String> {
+        let mut rows: Vec<Vec<(usize, BigFraction)>> = vec![Vec::new(); row_count];
+        for (row, col, value) in triplets.iter() {
+            if *row >= row_count || *col >= col_count {
+                return Err(format!("Index ({}, {}), dimensions {}x{}", row, col, row_count, col_count));
+            }
+            rows[*row].push((*col, value.clone()));
+        }
+        Ok(SparseMatrix::from_unsorted_rows(rows, col_count))
+    }
+
+    fn from_unsorted_rows(rows: Vec<Vec<(usize, BigFraction)>>, col_count: usize) -> SparseMatrix {
+        let mut matrix: SparseMatrix = SparseMatrix::zeros(0, col_count);
+        for mut row in rows {
+            row.sort_by_key(|(col, _)| *col);
+            let mut merged: Vec<(usize, BigFraction)> = Vec::with_capacity(row.len());
+            for (col, value) in row {
+                match merged.last_mut() {
+                    Some((last, sum)) if *last == col => *sum = sum.clone().add(value),
+                    _ => merged.push((col, value)),
+                }
+            }
+            matrix.push_row(merged.into_iter().filter(|(_, value)| !value.is_zero()));
+        }
+        matrix
+    }
+
+    fn push_row<I: Iterator<Item=(usize, BigFraction)>>(&mut self, entries: I) {
+        for (col, value) in entries {
+            self.col_indices.push(col);
+            self.values.push(value);
+        }
+        self.row_offsets.push(self.values.len());
+        self.row_count += 1;
+    }
+
+    pub fn from_dense(matrix: &BigMatrix) -> SparseMatrix {
+        let mut sparse: SparseMatrix = SparseMatrix::zeros(0, matrix.col_count());
+        for row in 0..matrix.row_count() {
+            let values: &[BigFraction] = matrix.row_view(row).expect("Row in range");
+            sparse.push_row(values.iter().cloned().enumerate().filter(|(_, value)| !value.is_zero()));
+        }
+        sparse
+    }
+
+    pub fn to_dense(&self) -> BigMatrix {
+        let mut matrix: BigMatrix = BigMatrix::zeros(self.row_count, self.col_count);
+        for row in 0..self.row_count {
+            for (col, value) in self.row_entries(row) {
+                matrix.set(row, col, value.clone()).expect("Index in range");
+            }
+        }
+        matrix
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    /// Number of stored nonzero entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The nonzero entries of a row as `(col, value)` sorted by column
+    pub fn row(&self, row: usize) -> Result<impl Iterator<Item=(usize, &BigFraction)>, String> {
+        if row >= self.row_count {
+            return Err(format!("Row {}, row count {}", row, self.row_count));
+        }
+        Ok(self.row_entries(row))
+    }
+
+    fn row_entries(&self, row: usize) -> impl Iterator<Item=(usize, &BigFraction)> {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        self.col_indices[range.clone()].iter().cloned().zip(self.values[range].iter())
+    }
+
+    fn find(&self, row: usize, col: usize) -> Result<usize, usize> {
+        let start: usize = self.row_offsets[row];
+        let end: usize = self.row_offsets[row + 1];
+        self.col_indices[start..end].binary_search(&col).map(|i| i + start).map_err(|i| i + start)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Result<BigFraction, String> {
+        if row >= self.row_count || col >= self.col_count {
+            return Err(format!("Index ({}, {}), dimensions {}x{}", row, col, self.row_count, self.col_count));
+        }
+        Ok(match self.find(row, col) {
+            Ok(index) => self.values[index].clone(),
+            Err(_) => BigFraction::get_zero(),
+        })
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: BigFraction) -> Result<BigFraction, String> {
+        if row >= self.row_count || col >= self.col_count {
+            return Err(format!("Index ({}, {}), dimensions {}x{}", row, col, self.row_count, self.col_count));
+        }
+        match (self.find(row, col), value.is_zero()) {
+            (Ok(index), false) => self.values[index] = value.clone(),
+            (Ok(index), true) => {
+                self.col_indices.remove(index);
+                self.values.remove(index);
+                self.row_offsets[row + 1..].iter_mut().for_each(|offset| *offset -= 1);
+            }
+            (Err(index), false) => {
+                self.col_indices.insert(index, col);
+                self.values.insert(index, value.clone());
+                self.row_offsets[row + 1..].iter_mut().for_each(|offset| *offset += 1);
+            }
+            (Err(_), true) => {}
+        }
+        Ok(value)
+    }
+
+    pub fn transpose(&self) -> SparseMatrix {
+        let mut counts: Vec<usize> = vec![0; self.col_count + 1];
+        for &col in self.col_indices.iter() {
+            counts[col + 1] += 1;
+        }
+        for col in 0..self.col_count {
+            counts[col + 1] += counts[col];
+        }
+        let row_offsets: Vec<usize> = counts.clone();
+        let mut col_indices: Vec<usize> = vec![0; self.nnz()];
+        let mut values: Vec<BigFraction> = vec![BigFraction::get_zero(); self.nnz()];
+        for row in 0..self.row_count {
+            for (col, value) in self.row_entries(row) {
+                col_indices[counts[col]] = row;
+                values[counts[col]] = value.clone();
+                counts[col] += 1;
+            }
+        }
+        SparseMatrix {
+            row_offsets,
+            col_indices,
+            values,
+            row_count: self.col_count,
+            col_count: self.row_count,
+        }
+    }
+
+    pub fn multiply(&self, other: &SparseMatrix) -> Result<SparseMatrix, String> {
+        if self.col_count != other.row_count {
+            return Err(format!("Can not multiply {}x{} by {}x{}", self.row_count, self.col_count, other.row_count, other.col_count));
+        }
+        let mut result: SparseMatrix = SparseMatrix::zeros(0, other.col_count);
+        let mut accumulator: Vec<Option<BigFraction>> = vec![None; other.col_count];
+        let mut touched: Vec<usize> = Vec::new();
+        for row in 0..self.row_count {
+            for (k, a) in self.row_entries(row) {
+                for (col, b) in other.row_entries(k) {
+                    let product: BigFraction = a.clone().mul(b.clone());
+                    accumulator[col] = Some(match accumulator[col].take() {
+                        Some(sum) => sum.add(product),
+                        None => {
+                            touched.push(col);
+                            product
+                        }
+                    });
+                }
+            }
+            touched.sort_unstable();
+            let entries: Vec<(usize, BigFraction)> = touched.drain(..)
+                .map(|col| (col, accumulator[col].take().expect("Touched column")))
+                .filter(|(_, value)| !value.is_zero())
+                .collect();
+            result.push_row(entries.into_iter());
+        }
+        Ok(result)
+    }
+
+    pub fn multiply_vector(&self, vector: &BigVector) -> Result<BigVector, String> {
+        if self.col_count != vector.dimension() {
+            return Err(format!("Can not multiply {}x{} by a vector of dimension {}", self.row_count, self.col_count, vector.dimension()));
+        }
+        let values: Vec<BigFraction> = vector.to_vec();
+        Ok(BigVector::from_fn(self.row_count, |row| {
+            self.row_entries(row).fold(BigFraction::get_zero(), |sum, (col, value)| sum.add(value.clone().mul(values[col].clone())))
+        }))
+    }
+
+    pub fn multiply_dense(&self, other: &BigMatrix) -> Result<BigMatrix, String> {
+        if self.col_count != other.row_count() {
+            return Err(format!("Can not multiply {}x{} by {}x{}", self.row_count, self.col_count, other.row_count(), other.col_count()));
+        }
+        let mut result: Vec<Vec<BigFraction>> = vec![vec![BigFraction::get_zero(); other.col_count()]; self.row_count];
+        for (row, target) in result.iter_mut().enumerate() {
+            for (k, a) in self.row_entries(row) {
+                for (value, b) in target.iter_mut().zip(other.row_view(k).expect("Row in range").iter()) {
+                    if !b.is_zero() {
+                        *value = value.clone().add(a.clone().mul(b.clone()));
+                    }
+                }
+            }
+        }
+        Ok(BigMatrix::from_row_vecs(result, other.col_count()))
+    }
+}
+
+impl From<&BigMatrix> for SparseMatrix {
+    fn from(matrix: &BigMatrix) -> Self {
+        SparseMatrix::from_dense(matrix)
+    }
+}
+
+impl From<&SparseMatrix> for BigMatrix {
+    fn from(matrix: &SparseMatrix) -> Self {
+        matrix.to_dense()
+    }
+}
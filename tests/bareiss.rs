@@ -0,0 +1,69 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use common::int_matrix;
+
+fn random_matrix(seed: &mut u64, row_count: usize, col_count: usize, bound: i64) -> BigMatrix {
+    BigMatrix::from_fn(row_count, col_count, |_, _| {
+        *seed = (seed.wrapping_mul(0x5DEECE66D).wrapping_add(0xB)) & ((1 << 48) - 1);
+        BigFraction::from((*seed >> 17) as i64 % (2 * bound + 1) - bound)
+    })
+}
+
+#[test]
+fn test_det_bareiss() {
+    let a: BigMatrix = int_matrix(&[&[2, 1, 1], &[4, -6, 0], &[-2, 7, 2]]);
+    assert_eq!(a.det_bareiss().unwrap(), BigInt::from(-16));
+    let b: BigMatrix = int_matrix(&[&[0, 1, 2], &[0, 0, 3], &[4, 5, 6]]);
+    assert_eq!(b.det_bareiss().unwrap(), BigInt::from(12));
+    let singular: BigMatrix = int_matrix(&[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]]);
+    assert_eq!(singular.det_bareiss().unwrap(), BigInt::from(0));
+    assert_eq!(BigMatrix::zeros(0, 0).det_bareiss().unwrap(), BigInt::from(1));
+}
+
+#[test]
+fn test_det_bareiss_errors() {
+    assert!(BigMatrix::zeros(2, 3).det_bareiss().is_err());
+    let rational: BigMatrix = BigMatrix::from_rows(&[BigVector::new(&[BigFraction::get_half()])]).unwrap();
+    assert!(rational.det_bareiss().is_err());
+    assert_eq!(rational.determinant().unwrap(), BigFraction::get_half());
+}
+
+#[test]
+fn test_det_bareiss_matches_lu() {
+    let mut seed: u64 = 12345;
+    for size in 1..8 {
+        let a: BigMatrix = random_matrix(&mut seed, size, size, 1000);
+        assert_eq!(BigFraction::from(a.det_bareiss().unwrap()), a.lu_decomposition().unwrap().det());
+        assert_eq!(a.determinant().unwrap(), a.lu_decomposition().unwrap().det());
+    }
+}
+
+#[test]
+fn test_echelon_bareiss() {
+    let a: BigMatrix = int_matrix(&[&[2, 1, 1], &[4, -6, 0], &[-2, 7, 2]]);
+    assert_eq!(a.echelon_bareiss().unwrap(), int_matrix(&[&[2, 1, 1], &[0, -16, -4], &[0, 0, -16]]));
+    let b: BigMatrix = int_matrix(&[&[1, 2, 3, 4], &[2, 4, 6, 8], &[1, 0, 1, 0]]);
+    let echelon: BigMatrix = b.echelon_bareiss().unwrap();
+    assert!(echelon.get_row(2).unwrap().is_zero());
+    assert_eq!(echelon.rank(), 2);
+    assert_eq!(BigMatrix::zeros(0, 3).echelon_bareiss().unwrap(), BigMatrix::zeros(0, 3));
+}
+
+#[test]
+fn test_rank_matches_rational() {
+    let mut seed: u64 = 987654321;
+    for &(rows, cols) in [(3, 5), (5, 3), (4, 4), (6, 2)].iter() {
+        let generators: BigMatrix = random_matrix(&mut seed, 2, cols, 20);
+        let mixing: BigMatrix = random_matrix(&mut seed, rows, 2, 20);
+        let low_rank: BigMatrix = mixing.multiply(&generators).unwrap();
+        let halved: BigMatrix = BigMatrix::from_fn(rows, cols, |i, j| low_rank.get(i, j).unwrap().div_int(BigInt::from(2)).unwrap());
+        assert_eq!(low_rank.rank(), halved.rank());
+        assert!(low_rank.rank() <= 2);
+        let full: BigMatrix = random_matrix(&mut seed, rows, cols, 20);
+        assert_eq!(full.rank(), full.row_echelon().rows().iter().filter(|row| !row.is_zero()).count());
+    }
+}
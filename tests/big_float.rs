@@ -0,0 +1,60 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_float::BigFloat;
+use common::fraction;
+
+fn float(value: i64, precision: u32) -> BigFloat {
+    BigFloat::from_int(&BigInt::from(value), precision)
+}
+
+#[test]
+fn test_arithmetic() {
+    let a: BigFloat = float(6, 64);
+    let b: BigFloat = float(-4, 64);
+    assert_eq!(a.clone() + b.clone(), float(2, 64));
+    assert_eq!(a.clone() - b.clone(), float(10, 64));
+    assert_eq!(a.clone() * b.clone(), float(-24, 64));
+    assert_eq!(a.clone() / b.clone(), BigFloat::from_fraction(&fraction(-3, 2), 64));
+    assert_eq!(-a.clone(), float(-6, 64));
+    assert_eq!((float(1, 64) / float(3, 64)).to_f64(), 1.0 / 3.0);
+    assert!(float(0, 64).is_zero());
+}
+
+#[test]
+fn test_rounding() {
+    let third: BigFloat = BigFloat::from_fraction(&fraction(1, 3), 8);
+    assert_eq!(third.get_precision(), 8);
+    assert_eq!(third.clone() * float(3, 8), float(1, 8));
+    // 2^80 + 1 is not representable with 53 bits
+    let large: BigInt = (BigInt::from(1) << 80usize) + 1;
+    assert_eq!(BigFloat::from_int(&large, 53).round(), BigInt::from(1) << 80usize);
+    assert_eq!(BigFloat::from_int(&large, 96).round(), large);
+    assert_eq!(BigFloat::from_fraction(&fraction(5, 2), 32).round(), BigInt::from(3));
+    assert_eq!(BigFloat::from_fraction(&fraction(-5, 2), 32).round(), BigInt::from(-2));
+    assert_eq!(BigFloat::from_fraction(&fraction(-7, 3), 32).round(), BigInt::from(-2));
+    assert_eq!(BigFloat::from_fraction(&fraction(1, 1000), 32).round(), BigInt::from(0));
+}
+
+#[test]
+fn test_ordering() {
+    let small: BigFloat = BigFloat::from_fraction(&fraction(1, 1 << 40), 64);
+    assert!(small > float(0, 64));
+    assert!(-small.clone() < float(0, 64));
+    assert!(float(3, 64) > float(2, 64));
+    assert!(float(-3, 64) < float(-2, 64));
+    assert!(float(1, 64) + small.clone() > float(1, 64));
+    // the smaller operand is absorbed below the precision
+    let tiny: BigFloat = BigFloat::from_fraction(&fraction(1, 1 << 40), 16);
+    assert_eq!(float(1, 16) + tiny, float(1, 16));
+    assert_eq!(float(5, 8).abs(), float(-5, 8).abs());
+}
+
+#[test]
+fn test_large_exponents() {
+    let huge: BigInt = BigInt::from(3) << 5000usize;
+    let value: BigFloat = BigFloat::from_int(&huge, 64);
+    assert_eq!(value.to_f64(), f64::INFINITY);
+    assert_eq!((value.clone() / BigFloat::from_int(&huge, 64)).round(), BigInt::from(1));
+    assert_eq!((value.clone() * value.clone() / value.clone()).round(), huge);
+}
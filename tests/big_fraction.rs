@@ -337,3 +337,17 @@ fn test_log_1() {
 }
 
 
+
+#[test]
+fn test_operator_traits() {
+    let a: BigFraction = BigFraction::new(BigInt::from(7), BigInt::from(11)).expect("Correct");
+    let b: BigFraction = BigFraction::new(BigInt::from(13), BigInt::from(17)).expect("Correct");
+    assert_eq!(a.clone() + b.clone(), BigFraction::new(BigInt::from(262), BigInt::from(187)).expect("Correct"));
+    assert_eq!(a.clone() - b.clone(), BigFraction::new(BigInt::from(-24), BigInt::from(187)).expect("Correct"));
+    assert_eq!(a.clone() * b.clone(), BigFraction::new(BigInt::from(91), BigInt::from(187)).expect("Correct"));
+    assert_eq!(a.clone() / b.clone(), BigFraction::new(BigInt::from(119), BigInt::from(143)).expect("Correct"));
+    assert_eq!(-a.clone(), BigFraction::new(BigInt::from(-7), BigInt::from(11)).expect("Correct"));
+    assert!(a < b);
+    assert!(-b < a);
+    assert_eq!(BigFraction::from(BigInt::from(3)), BigFraction::from(3i64));
+}
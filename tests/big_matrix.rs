@@ -0,0 +1,113 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::{BigVector, VectorFormat};
+use common::{fraction, int_vector, int_matrix};
+
+#[test]
+fn test_constructors() {
+    let zeros: BigMatrix = BigMatrix::zeros(2, 3);
+    assert_eq!(zeros.row_count(), 2);
+    assert_eq!(zeros.col_count(), 3);
+    assert_eq!(zeros, int_matrix(&[&[0, 0, 0], &[0, 0, 0]]));
+    assert_eq!(BigMatrix::identity(2), int_matrix(&[&[1, 0], &[0, 1]]));
+    assert_eq!(BigMatrix::from_fn(2, 2, |i, j| fraction((i * 2 + j) as i64, 1)), int_matrix(&[&[0, 1], &[2, 3]]));
+    let columns: BigMatrix = BigMatrix::from_columns(&[int_vector(&[1, 2]), int_vector(&[3, 4])]).unwrap();
+    assert_eq!(columns, int_matrix(&[&[1, 3], &[2, 4]]));
+    assert!(BigMatrix::from_rows(&[int_vector(&[1, 2]), int_vector(&[3])]).is_err());
+}
+
+#[test]
+fn test_get_set() {
+    let mut a: BigMatrix = int_matrix(&[&[1, 2], &[3, 4]]);
+    assert_eq!(a.get(1, 0).unwrap(), fraction(3, 1));
+    assert!(a.get(2, 0).is_err());
+    assert!(a.get(0, 2).is_err());
+    a.set(0, 1, fraction(1, 2)).unwrap();
+    assert_eq!(a.get(0, 1).unwrap(), fraction(1, 2));
+    assert!(a.set(0, 2, fraction(1, 2)).is_err());
+}
+
+#[test]
+fn test_row_and_column_access() {
+    let mut a: BigMatrix = int_matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+    assert_eq!(a.get_row(1).unwrap(), int_vector(&[4, 5, 6]));
+    assert_eq!(a.get_column(2).unwrap(), int_vector(&[3, 6]));
+    assert_eq!(a.row_view(0).unwrap(), &[fraction(1, 1), fraction(2, 1), fraction(3, 1)]);
+    assert!(a.get_row(2).is_err());
+    assert!(a.get_column(3).is_err());
+    a.set_row(0, &int_vector(&[7, 8, 9])).unwrap();
+    a.set_column(1, &int_vector(&[0, 0])).unwrap();
+    assert_eq!(a, int_matrix(&[&[7, 0, 9], &[4, 0, 6]]));
+    assert!(a.set_row(0, &int_vector(&[1])).is_err());
+    assert!(a.set_column(0, &int_vector(&[1])).is_err());
+    assert_eq!(a.rows(), vec![int_vector(&[7, 0, 9]), int_vector(&[4, 0, 6])]);
+    assert_eq!(a.columns(), vec![int_vector(&[7, 4]), int_vector(&[0, 0]), int_vector(&[9, 6])]);
+}
+
+#[test]
+fn test_multiply() {
+    let a: BigMatrix = int_matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+    let b: BigMatrix = int_matrix(&[&[7, 8], &[9, 10], &[11, 12]]);
+    assert_eq!(a.multiply(&b).unwrap(), int_matrix(&[&[58, 64], &[139, 154]]));
+    assert_eq!(a.multiply(&BigMatrix::identity(3)).unwrap(), a);
+    assert!(a.multiply(&a).is_err());
+}
+
+#[test]
+fn test_multiply_vector() {
+    let a: BigMatrix = int_matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+    let v: BigVector = BigVector::new(&[fraction(1, 2), fraction(0, 1), fraction(-1, 1)]);
+    assert_eq!(a.multiply_vector(&v).unwrap(), BigVector::new(&[fraction(-5, 2), fraction(-4, 1)]));
+    assert!(a.multiply_vector(&int_vector(&[1, 2])).is_err());
+}
+
+#[test]
+fn test_transpose_and_submatrix() {
+    let a: BigMatrix = int_matrix(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]);
+    assert_eq!(a.transpose(), int_matrix(&[&[1, 4, 7], &[2, 5, 8], &[3, 6, 9]]));
+    assert_eq!(a.transpose().transpose(), a);
+    assert_eq!(a.submatrix(1, 1, 2, 2).unwrap(), int_matrix(&[&[5, 6], &[8, 9]]));
+    assert_eq!(a.submatrix(0, 2, 3, 1).unwrap(), int_matrix(&[&[3], &[6], &[9]]));
+    assert!(a.submatrix(2, 2, 2, 1).is_err());
+    assert!(BigMatrix::zeros(2, 2).submatrix(usize::MAX, 0, 2, 1).is_err());
+    assert!(BigMatrix::zeros(2, 2).submatrix(0, 1, 1, usize::MAX).is_err());
+}
+
+#[test]
+fn test_row_operations() {
+    let mut a: BigMatrix = int_matrix(&[&[1, 2], &[3, 4]]);
+    a.swap_rows(0, 1).unwrap();
+    assert_eq!(a, int_matrix(&[&[3, 4], &[1, 2]]));
+    a.add_row_multiple(0, 1, &fraction(-3, 1)).unwrap();
+    assert_eq!(a, int_matrix(&[&[0, -2], &[1, 2]]));
+    assert!(a.swap_rows(0, 2).is_err());
+    assert!(a.add_row_multiple(2, 0, &fraction(1, 1)).is_err());
+}
+
+#[test]
+fn test_display() {
+    let a: BigMatrix = BigMatrix::from_rows(&[BigVector::new(&[fraction(1, 1), fraction(2, 3)]), int_vector(&[-4, 0])]).unwrap();
+    assert_eq!(a.to_string(), "{{1, 2/3}, {-4, 0}}");
+    assert_eq!(a.format(VectorFormat::Sage), "[[1, 2/3], [-4, 0]]");
+    assert_eq!(a.format(VectorFormat::Fplll), "[[1 2/3]\n[-4 0]\n]");
+}
+
+#[test]
+fn test_common_denominator() {
+    let a: BigMatrix = BigMatrix::from_rows(&[
+        BigVector::new(&[fraction(1, 3), fraction(2, 1)]),
+        BigVector::new(&[fraction(1, 4), fraction(5, 6)]),
+    ]).unwrap();
+    let (denominator, scaled) = a.common_denominator();
+    assert_eq!(denominator, BigInt::from(12));
+    assert_eq!(scaled, int_matrix(&[&[4, 24], &[3, 10]]));
+    let (denominators, scaled) = a.row_denominators();
+    assert_eq!(denominators, vec![BigInt::from(3), BigInt::from(12)]);
+    assert_eq!(scaled, int_matrix(&[&[1, 6], &[3, 10]]));
+    let (denominators, scaled) = a.column_denominators();
+    assert_eq!(denominators, vec![BigInt::from(12), BigInt::from(6)]);
+    assert_eq!(scaled, int_matrix(&[&[4, 12], &[3, 5]]));
+    assert_eq!(BigMatrix::identity(2).common_denominator(), (BigInt::from(1), BigMatrix::identity(2)));
+}
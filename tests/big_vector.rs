@@ -0,0 +1,208 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_vector::{BigVector, VectorFormat};
+use std::ops::Bound;
+use common::{fraction, int_vector};
+
+#[test]
+fn test_dot() {
+    let a: BigVector = int_vector(&[1, 2, 3]);
+    let b: BigVector = int_vector(&[4, -5, 6]);
+    assert_eq!(a.dot(&b).unwrap(), fraction(12, 1));
+    assert!(a.dot(&int_vector(&[1, 2])).is_err());
+}
+
+#[test]
+fn test_norm_l1() {
+    let a: BigVector = BigVector::new(&[fraction(1, 2), fraction(-2, 3), fraction(-4, 1)]);
+    assert_eq!(a.norm_l1(), fraction(31, 6));
+}
+
+#[test]
+fn test_norm_linf() {
+    let a: BigVector = BigVector::new(&[fraction(1, 2), fraction(-9, 2), fraction(4, 1)]);
+    assert_eq!(a.norm_linf(), fraction(9, 2));
+    assert_eq!(int_vector(&[0, 0]).norm_linf(), BigFraction::get_zero());
+}
+
+#[test]
+fn test_norm_l2_exact() {
+    let a: BigVector = int_vector(&[3, -4]);
+    assert_eq!(a.norm_l2(), (BigInt::from(5), BigInt::from(5)));
+}
+
+#[test]
+fn test_norm_l2_bracket() {
+    let a: BigVector = int_vector(&[1, 1, 1]);
+    assert_eq!(a.norm_l2(), (BigInt::from(1), BigInt::from(2)));
+    let b: BigVector = BigVector::new(&[fraction(5, 2), fraction(0, 1)]);
+    assert_eq!(b.norm_l2(), (BigInt::from(2), BigInt::from(3)));
+}
+
+#[test]
+fn test_weighted_norm_sq() {
+    let a: BigVector = int_vector(&[1, 2, 3]);
+    let weights: BigVector = BigVector::new(&[fraction(1, 1), fraction(1, 4), fraction(2, 1)]);
+    assert_eq!(a.weighted_norm_sq(&weights).unwrap(), fraction(20, 1));
+    assert!(a.weighted_norm_sq(&int_vector(&[1])).is_err());
+}
+
+#[test]
+fn test_quadratic_form() {
+    let a: BigVector = int_vector(&[1, 2]);
+    let gram: Vec<BigVector> = vec![int_vector(&[2, 1]), int_vector(&[1, 3])];
+    assert_eq!(a.quadratic_form(&gram).unwrap(), fraction(18, 1));
+    let identity: Vec<BigVector> = vec![int_vector(&[1, 0]), int_vector(&[0, 1])];
+    assert_eq!(a.quadratic_form(&identity).unwrap(), a.magnitude_sq());
+    assert!(a.quadratic_form(&[int_vector(&[1, 0])]).is_err());
+    assert!(a.quadratic_form(&[int_vector(&[1, 0, 0]), int_vector(&[0, 1, 0])]).is_err());
+}
+
+#[test]
+fn test_display() {
+    let a: BigVector = BigVector::new(&[fraction(1, 1), fraction(2, 3), fraction(-4, 1)]);
+    assert_eq!(a.to_string(), "{1, 2/3, -4}");
+    assert_eq!(a.format(VectorFormat::LattiCG), "{1, 2/3, -4}");
+    assert_eq!(a.format(VectorFormat::Sage), "[1, 2/3, -4]");
+    assert_eq!(a.format(VectorFormat::Fplll), "[1 2/3 -4]");
+    assert_eq!(BigVector::new(&[]).to_string(), "{}");
+}
+
+#[test]
+fn test_parse_formats() {
+    let expected: BigVector = BigVector::new(&[fraction(1, 1), fraction(2, 3), fraction(-4, 1)]);
+    assert_eq!("{1, 2/3, -4}".parse::<BigVector>().unwrap(), expected);
+    assert_eq!("[1, 2/3, -4]".parse::<BigVector>().unwrap(), expected);
+    assert_eq!("[1 2/3 -4]".parse::<BigVector>().unwrap(), expected);
+    assert_eq!("  [ 1\t2/3\n-4 ] ".parse::<BigVector>().unwrap(), expected);
+    assert_eq!("[]".parse::<BigVector>().unwrap().dimension(), 0);
+}
+
+#[test]
+fn test_parse_round_trip() {
+    let a: BigVector = BigVector::new(&[fraction(-7, 11), fraction(0, 1), fraction(281474976710656, 10)]);
+    for style in [VectorFormat::LattiCG, VectorFormat::Sage, VectorFormat::Fplll].iter() {
+        assert_eq!(a.format(*style).parse::<BigVector>().unwrap(), a);
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!("".parse::<BigVector>().unwrap_err().to_string(), "cannot parse vector from empty string");
+    assert_eq!("{1, 2]".parse::<BigVector>().unwrap_err().to_string(), "vector must be enclosed in matching {} or []");
+    assert_eq!("1, 2".parse::<BigVector>().unwrap_err().to_string(), "vector must be enclosed in matching {} or []");
+    assert_eq!("[1, a]".parse::<BigVector>().unwrap_err().to_string(), "invalid entry found in vector");
+    assert_eq!("[1, , 2]".parse::<BigVector>().unwrap_err().to_string(), "invalid entry found in vector");
+    assert_eq!("[1 2, 3 4]".parse::<BigVector>().unwrap_err().to_string(), "invalid entry found in vector");
+    assert_eq!("[1/0]".parse::<BigVector>().unwrap_err().to_string(), "invalid entry found in vector");
+}
+
+#[test]
+fn test_zeros_and_basis() {
+    assert_eq!(BigVector::zeros(3), int_vector(&[0, 0, 0]));
+    assert!(BigVector::zeros(3).is_zero());
+    assert_eq!(BigVector::basis(3, 1).unwrap(), int_vector(&[0, 1, 0]));
+    assert!(BigVector::basis(3, 3).is_err());
+}
+
+#[test]
+fn test_from_fn() {
+    let a: BigVector = BigVector::from_fn(4, |i| fraction(1, i as i64 + 1));
+    assert_eq!(a, BigVector::new(&[fraction(1, 1), fraction(1, 2), fraction(1, 3), fraction(1, 4)]));
+}
+
+#[test]
+fn test_concat_and_subvector() {
+    let a: BigVector = int_vector(&[1, 2]);
+    let b: BigVector = int_vector(&[3, 4, 5]);
+    let c: BigVector = a.concat(&b);
+    assert_eq!(c, int_vector(&[1, 2, 3, 4, 5]));
+    assert_eq!(c.subvector(1..3).unwrap(), int_vector(&[2, 3]));
+    assert_eq!(c.subvector(3..).unwrap(), int_vector(&[4, 5]));
+    assert_eq!(c.subvector(..=1).unwrap(), a);
+    assert_eq!(c.subvector(2..2).unwrap().dimension(), 0);
+    assert!(c.subvector(4..6).is_err());
+    assert!(c.subvector(..=usize::MAX).is_err());
+    assert!(c.subvector((Bound::Excluded(usize::MAX), Bound::Unbounded)).is_err());
+}
+
+#[test]
+fn test_resize_insert_remove() {
+    let mut a: BigVector = int_vector(&[1, 2, 3]);
+    a.resize(5);
+    assert_eq!(a, int_vector(&[1, 2, 3, 0, 0]));
+    a.resize(2);
+    assert_eq!(a, int_vector(&[1, 2]));
+    a.insert(0, fraction(7, 1)).unwrap();
+    a.insert(3, fraction(9, 1)).unwrap();
+    assert_eq!(a, int_vector(&[7, 1, 2, 9]));
+    assert!(a.insert(5, fraction(1, 1)).is_err());
+    assert_eq!(a.remove(1).unwrap(), fraction(1, 1));
+    assert_eq!(a, int_vector(&[7, 2, 9]));
+    assert!(a.remove(3).is_err());
+}
+
+#[test]
+fn test_map_and_zip_with() {
+    let a: BigVector = int_vector(&[1, -2, 3]);
+    let b: BigVector = int_vector(&[4, 5, 6]);
+    assert_eq!(a.map(|x| x.clone().negate()), int_vector(&[-1, 2, -3]));
+    assert_eq!(a.zip_with(&b, |x, y| x.clone().mul(y.clone())).unwrap(), int_vector(&[4, -10, 18]));
+    assert!(a.zip_with(&int_vector(&[1]), |x, _| x.clone()).is_err());
+}
+
+#[test]
+fn test_reduce_mod() {
+    let modulus: BigInt = BigInt::from(1u64 << 48);
+    let a: BigVector = BigVector::new_int(&[BigInt::from(-1), BigInt::from(1u64 << 48) + 5, BigInt::from(7)]);
+    let expected: BigVector = BigVector::new_int(&[BigInt::from((1u64 << 48) - 1), BigInt::from(5), BigInt::from(7)]);
+    assert_eq!(a.reduce_mod(&modulus).unwrap(), expected);
+}
+
+#[test]
+fn test_reduce_mod_centered() {
+    let a: BigVector = int_vector(&[0, 4, 5, 6, -5, 13]);
+    assert_eq!(a.reduce_mod_centered(&BigInt::from(10)).unwrap(), int_vector(&[0, 4, 5, -4, 5, 3]));
+    assert_eq!(int_vector(&[1, 2]).reduce_mod_centered(&BigInt::from(3)).unwrap(), int_vector(&[1, -1]));
+}
+
+#[test]
+fn test_reduce_mod_errors() {
+    assert!(int_vector(&[1]).reduce_mod(&BigInt::from(0)).is_err());
+    assert!(int_vector(&[1]).reduce_mod(&BigInt::from(-3)).is_err());
+    assert!(BigVector::new(&[fraction(1, 2)]).reduce_mod(&BigInt::from(3)).is_err());
+}
+
+#[test]
+fn test_is_congruent() {
+    let modulus: BigInt = BigInt::from(16);
+    assert!(int_vector(&[1, -1, 20]).is_congruent(&int_vector(&[17, 15, 4]), &modulus).unwrap());
+    assert!(!int_vector(&[1, -1, 20]).is_congruent(&int_vector(&[17, 15, 5]), &modulus).unwrap());
+    assert!(int_vector(&[1]).is_congruent(&int_vector(&[1, 2]), &modulus).is_err());
+}
+
+#[test]
+fn test_mod_inverse_scale() {
+    let modulus: BigInt = BigInt::from(1u64 << 48);
+    let multiplier: BigInt = BigInt::from(0x5DEECE66Du64);
+    let a: BigVector = int_vector(&[1, 2, 0x5DEECE66D]);
+    let scaled: BigVector = a.mod_inverse_scale(&multiplier, &modulus).unwrap();
+    let back: BigVector = scaled.map(|x| x.clone().mul_int(multiplier.clone()));
+    assert!(back.is_congruent(&a, &modulus).unwrap());
+    assert_eq!(scaled.get(2).unwrap(), fraction(1, 1));
+    assert!(a.mod_inverse_scale(&BigInt::from(2), &modulus).is_err());
+}
+
+#[test]
+fn test_common_denominator() {
+    let v: BigVector = BigVector::new(&[fraction(1, 3), fraction(-5, 2), fraction(4, 1), fraction(7, 6)]);
+    let (denominator, scaled) = v.common_denominator();
+    assert_eq!(denominator, BigInt::from(6));
+    assert_eq!(scaled, int_vector(&[2, -15, 24, 7]));
+    let (denominator, scaled) = int_vector(&[3, 0, -1]).common_denominator();
+    assert_eq!(denominator, BigInt::from(1));
+    assert_eq!(scaled, int_vector(&[3, 0, -1]));
+    assert_eq!(BigVector::zeros(0).common_denominator().0, BigInt::from(1));
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::decomposition::ldl_decomposition::LDLDecomposition;
+use latticg::math::lattice::bkz::{bkz, BKZParams, BKZResult, LinearPruning};
+use latticg::math::lattice::lll::{lll, LLLParams};
+use latticg::math::lattice::lll_fp::lll_fp;
+use std::sync::Arc;
+use common::{java_lcg_lattice, shortest_norm_sq};
+
+fn check_basis(basis: &BigMatrix, result: &BKZResult) {
+    let u: &BigMatrix = result.get_transformations();
+    assert_eq!(u.determinant().unwrap().abs(), BigFraction::get_one());
+    assert_eq!(&u.multiply(basis).unwrap(), result.get_reduced_basis());
+    let reduced: &BigMatrix = result.get_reduced_basis();
+    let ldl: LDLDecomposition = reduced.gram_matrix().ldl_decomposition().unwrap();
+    let half: BigFraction = BigFraction::new(BigInt::from(51), BigInt::from(100)).unwrap();
+    for i in 0..reduced.row_count() {
+        for j in 0..i {
+            assert!(ldl.get_mu(i, j).unwrap().abs() <= half);
+        }
+    }
+}
+
+#[test]
+fn test_full_block_finds_shortest_vector() {
+    let basis: BigMatrix = java_lcg_lattice(5);
+    let params: BKZParams = BKZParams::new(5);
+    let result: BKZResult = bkz(&basis, &params).unwrap();
+    check_basis(&basis, &result);
+    let first: BigFraction = result.get_reduced_basis().get_row(0).unwrap().magnitude_sq();
+    let lll_basis: BigMatrix = lll(&basis, &LLLParams::default()).unwrap().get_reduced_basis().clone();
+    assert_eq!(first, shortest_norm_sq(&lll_basis, 3));
+}
+
+#[test]
+fn test_improves_on_lll() {
+    let lcg: BigMatrix = java_lcg_lattice(12);
+    let rational: BigMatrix = BigMatrix::from_fn(12, 12, |row, col| lcg.get(row, col).unwrap() / BigFraction::from(3));
+    for basis in [java_lcg_lattice(24), rational].iter() {
+        let params: BKZParams = BKZParams::new(10);
+        let result: BKZResult = bkz(basis, &params).unwrap();
+        check_basis(basis, &result);
+        let lll_first: BigFraction = lll_fp(basis, &LLLParams::default()).unwrap().get_reduced_basis().get_row(0).unwrap().magnitude_sq();
+        let bkz_first: BigFraction = result.get_reduced_basis().get_row(0).unwrap().magnitude_sq();
+        assert!(bkz_first <= lll_first);
+        let stats = result.get_tour_stats();
+        assert!(!stats.is_empty());
+        for (i, tour) in stats.iter().enumerate() {
+            assert_eq!(tour.tour, i);
+            assert!(tour.root_hermite_factor > 0.9 && tour.root_hermite_factor < 1.1);
+        }
+        // the statistics describe the basis itself, not its integral rescaling
+        let last = stats.last().unwrap();
+        assert!((last.first_norm * last.first_norm - bkz_first.to_double()).abs() <= 1e-6 * bkz_first.to_double());
+    }
+}
+
+#[test]
+fn test_early_termination_and_pruning() {
+    let basis: BigMatrix = java_lcg_lattice(20);
+    let params: BKZParams = BKZParams::new(8).with_max_tours(1).with_pruning(Arc::new(LinearPruning)).with_node_limit(Some(10_000));
+    let result: BKZResult = bkz(&basis, &params).unwrap();
+    check_basis(&basis, &result);
+    assert_eq!(result.get_tour_stats().len(), 1);
+    assert!(result.get_tour_stats()[0].nodes > 0);
+}
+
+#[test]
+fn test_reduce_on_other_thread() {
+    let basis: BigMatrix = java_lcg_lattice(10);
+    let params: BKZParams = BKZParams::new(4).with_pruning(Arc::new(LinearPruning));
+    let expected: BKZResult = bkz(&basis, &params).unwrap();
+    let reduced: BigMatrix = std::thread::spawn(move || bkz(&basis, &params).unwrap().get_reduced_basis().clone()).join().unwrap();
+    assert_eq!(&reduced, expected.get_reduced_basis());
+}
+
+#[test]
+fn test_invalid_params() {
+    let basis: BigMatrix = BigMatrix::identity(3);
+    assert!(bkz(&basis, &BKZParams::new(1)).is_err());
+    assert!(bkz(&basis, &BKZParams::new(2).with_gh_factor(Some(0.0))).is_err());
+    assert!(bkz(&basis, &BKZParams::new(2).with_gh_factor(Some(1.1))).is_ok());
+    let result: BKZResult = bkz(&basis, &BKZParams::new(10)).unwrap();
+    assert_eq!(result.get_reduced_basis(), &basis);
+    assert_eq!(result.get_rank(), 3);
+}
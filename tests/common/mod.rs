@@ -0,0 +1,76 @@
+// every test crate compiles its own copy and uses only some of the helpers
+#![allow(dead_code)]
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+
+pub fn fraction(ntor: i64, dtor: i64) -> BigFraction {
+    BigFraction::new(BigInt::from(ntor), BigInt::from(dtor)).expect("Correct")
+}
+
+pub fn int_vector(values: &[i64]) -> BigVector {
+    BigVector::new_int(&values.iter().map(|&x| BigInt::from(x)).collect::<Vec<BigInt>>())
+}
+
+pub fn int_matrix(rows: &[&[i64]]) -> BigMatrix {
+    BigMatrix::from_rows(&rows.iter().map(|row| int_vector(row)).collect::<Vec<BigVector>>()).expect("Correct")
+}
+
+pub fn big_int_matrix(rows: &[Vec<BigInt>]) -> BigMatrix {
+    BigMatrix::from_rows(&rows.iter().map(|row| BigVector::new_int(row)).collect::<Vec<BigVector>>()).expect("Correct")
+}
+
+pub fn is_unimodular(u: &BigMatrix) -> bool {
+    let det: BigFraction = u.determinant().unwrap();
+    u.is_integral() && (det == BigFraction::get_one() || det == BigFraction::get_minus_one())
+}
+
+/// Truncated LCG lattice, the first row holds the multiplier powers and the rest are `m·e_i`
+pub fn lcg_lattice(multiplier: &BigInt, modulus: &BigInt, dimension: usize) -> BigMatrix {
+    let mut rows: Vec<Vec<BigInt>> = Vec::with_capacity(dimension);
+    let mut power: BigInt = BigInt::from(1);
+    let mut first: Vec<BigInt> = Vec::with_capacity(dimension);
+    for _ in 0..dimension {
+        first.push(power.clone());
+        power = power * multiplier % modulus;
+    }
+    rows.push(first);
+    for i in 1..dimension {
+        let mut row: Vec<BigInt> = vec![BigInt::from(0); dimension];
+        row[i] = modulus.clone();
+        rows.push(row);
+    }
+    big_int_matrix(&rows)
+}
+
+/// The lattice of `dimension` consecutive outputs of `java.util.Random`, `x -> 25214903917 x mod 2^48`
+pub fn java_lcg_lattice(dimension: usize) -> BigMatrix {
+    lcg_lattice(&BigInt::from(25214903917u64), &(BigInt::from(1) << 48usize), dimension)
+}
+
+/// Squared norm of the shortest nonzero combination with coefficients in `[-bound, bound]`, a brute force
+/// enough for the small reduced bases of the tests
+pub fn shortest_norm_sq(basis: &BigMatrix, bound: i64) -> BigFraction {
+    let rows: Vec<BigVector> = basis.rows();
+    let width: i64 = 2 * bound + 1;
+    let mut best: Option<BigFraction> = None;
+    for index in 0..width.pow(rows.len() as u32) {
+        let mut rest: i64 = index;
+        let mut vector: BigVector = BigVector::zeros(basis.col_count());
+        for row in rows.iter() {
+            let coefficient: i64 = rest % width - bound;
+            rest /= width;
+            vector = vector.zip_with(row, |a, b| a.clone() + b.clone() * BigFraction::from(coefficient)).unwrap();
+        }
+        if !vector.is_zero() {
+            let norm: BigFraction = vector.magnitude_sq();
+            best = match best {
+                Some(best) if best <= norm => Some(best),
+                _ => Some(norm),
+            };
+        }
+    }
+    best.unwrap()
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::lattice::construct::{kannan_embedding, kernel_lattice_mod, orthogonal_lattice, q_ary_lattice};
+use common::{fraction, int_vector, int_matrix};
+
+/// Whether `vector` is an integer combination of the rows of the square `basis`
+fn contains(basis: &BigMatrix, vector: &BigVector) -> bool {
+    basis.transpose().solve(vector).unwrap().is_integral()
+}
+
+#[test]
+fn test_kannan_embedding() {
+    let basis: BigMatrix = int_matrix(&[&[3, 1], &[1, 4]]);
+    let target: BigVector = BigVector::new(&[fraction(7, 2), BigFraction::from(2)]);
+    let embedded: BigMatrix = kannan_embedding(&basis, &target, &BigFraction::from(1)).unwrap();
+    assert_eq!(embedded, BigMatrix::from_rows(&[
+        int_vector(&[3, 1, 0]),
+        int_vector(&[1, 4, 0]),
+        BigVector::new(&[fraction(7, 2), BigFraction::from(2), BigFraction::from(1)]),
+    ]).unwrap());
+    assert!(kannan_embedding(&basis, &target, &BigFraction::get_zero()).is_err());
+    assert!(kannan_embedding(&basis, &int_vector(&[1, 2, 3]), &BigFraction::from(1)).is_err());
+}
+
+#[test]
+fn test_q_ary_lcg_lattice() {
+    let a: i64 = 25214903917;
+    let m: i64 = 1 << 48;
+    let mut powers: Vec<i64> = vec![1];
+    for _ in 1..4 {
+        let last: i128 = *powers.last().unwrap() as i128;
+        powers.push((last * a as i128 % m as i128) as i64);
+    }
+    let lattice: BigMatrix = q_ary_lattice(&int_matrix(&[&powers]), &BigInt::from(m)).unwrap();
+    assert_eq!(lattice.row_count(), 4);
+    assert_eq!(lattice.determinant().unwrap(), BigFraction::from(BigInt::from(m).pow(3)));
+    assert!(contains(&lattice, &int_vector(&powers)));
+    assert!(contains(&lattice, &int_vector(&[0, 0, m, 0])));
+    assert!(!contains(&lattice, &int_vector(&[1, 0, 0, 0])));
+    assert!(q_ary_lattice(&int_matrix(&[&powers]), &BigInt::from(0)).is_err());
+}
+
+#[test]
+fn test_kernel_lattice_mod() {
+    let system: BigMatrix = int_matrix(&[&[1, 2, 3], &[0, 5, 1]]);
+    let lattice: BigMatrix = kernel_lattice_mod(&system, &BigInt::from(12)).unwrap();
+    assert_eq!(lattice.row_count(), 3);
+    for row in lattice.rows() {
+        let image: BigVector = system.multiply_vector(&row).unwrap();
+        assert!(image.iter().all(|x| x.get_numerator() % 12 == BigInt::from(0)));
+    }
+    // the solutions have index |image of A mod 12| = 144 in Z^3 since A has a unimodular 2x2 minor
+    assert_eq!(lattice.determinant().unwrap(), BigFraction::from(144));
+    assert!(contains(&lattice, &int_vector(&[12, 0, 0])));
+    assert!(contains(&lattice, &int_vector(&[1, 1, -5])));
+}
+
+#[test]
+fn test_orthogonal_lattice() {
+    let vectors: BigMatrix = BigMatrix::from_rows(&[BigVector::new(&[fraction(1, 2), fraction(1, 2), fraction(1, 2), BigFraction::from(0)])]).unwrap();
+    let lattice: BigMatrix = orthogonal_lattice(&vectors).unwrap();
+    assert_eq!(lattice.row_count(), 3);
+    for row in lattice.rows() {
+        assert!(row.is_integral());
+        assert_eq!(row.dot(&vectors.get_row(0).unwrap()).unwrap(), BigFraction::get_zero());
+    }
+    // the orthogonal lattice of a primitive v has covolume |v|
+    assert_eq!(lattice.gram_matrix().determinant().unwrap(), BigFraction::from(3));
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::lattice::cvp::{babai_nearest_plane, babai_round, cvp_enumerate, CVPSolution};
+use common::{fraction, int_vector, int_matrix};
+
+fn check_solution(basis: &BigMatrix, target: &BigVector, solution: &CVPSolution) {
+    assert!(solution.get_coefficients().is_integral());
+    assert_eq!(&basis.transpose().multiply_vector(solution.get_coefficients()).unwrap(), solution.get_vector());
+    assert_eq!(&distance_sq(solution.get_vector(), target), solution.get_distance_sq());
+}
+
+fn distance_sq(a: &BigVector, b: &BigVector) -> BigFraction {
+    a.zip_with(b, |x, y| x.clone() - y.clone()).unwrap().magnitude_sq()
+}
+
+#[test]
+fn test_babai() {
+    let basis: BigMatrix = int_matrix(&[&[1, 0], &[0, 1]]);
+    let target: BigVector = BigVector::new(&[fraction(12, 5), fraction(-8, 5)]);
+    for solution in [babai_round(&basis, &target).unwrap(), babai_nearest_plane(&basis, &target).unwrap()].iter() {
+        check_solution(&basis, &target, solution);
+        assert_eq!(solution.get_vector(), &int_vector(&[2, -2]));
+    }
+    // on a skewed basis rounding goes astray while the nearest plane stays close
+    let skewed: BigMatrix = int_matrix(&[&[1, 0], &[99, 1]]);
+    let target: BigVector = BigVector::new(&[fraction(1, 2), fraction(1, 2)]);
+    let round: CVPSolution = babai_round(&skewed, &target).unwrap();
+    let plane: CVPSolution = babai_nearest_plane(&skewed, &target).unwrap();
+    check_solution(&skewed, &target, &round);
+    check_solution(&skewed, &target, &plane);
+    assert_eq!(plane.get_distance_sq(), &fraction(1, 2));
+    assert!(round.get_distance_sq() >= plane.get_distance_sq());
+}
+
+#[test]
+fn test_enumerate_matches_brute_force() {
+    let basis: BigMatrix = int_matrix(&[&[3, 1], &[1, 4]]);
+    let target: BigVector = BigVector::new(&[fraction(7, 2), fraction(5, 3)]);
+    let radius: BigFraction = BigFraction::from(40);
+    let solutions: Vec<CVPSolution> = cvp_enumerate(&basis, &target, Some(&radius)).unwrap();
+    for solution in solutions.iter() {
+        check_solution(&basis, &target, solution);
+    }
+    assert!(solutions.windows(2).all(|pair| pair[0].get_distance_sq() <= pair[1].get_distance_sq()));
+    // (x, y) lies in the lattice of determinant 11 exactly when 4x - y = 0 mod 11
+    let mut expected: Vec<Vec<BigFraction>> = Vec::new();
+    for x in -10..=20i64 {
+        for y in -10..=20i64 {
+            let point: BigVector = int_vector(&[x, y]);
+            if (4 * x - y).rem_euclid(11) == 0 && distance_sq(&point, &target) <= radius {
+                expected.push(point.to_vec());
+            }
+        }
+    }
+    let mut found: Vec<Vec<BigFraction>> = solutions.iter().map(|solution| solution.get_vector().to_vec()).collect();
+    found.sort();
+    expected.sort();
+    assert_eq!(found, expected);
+    // the default radius lists the closest vectors
+    let closest: Vec<CVPSolution> = cvp_enumerate(&basis, &target, None).unwrap();
+    assert_eq!(closest[0], solutions[0]);
+}
+
+#[test]
+fn test_target_outside_span() {
+    let basis: BigMatrix = int_matrix(&[&[1, 0, 0], &[0, 1, 0]]);
+    let target: BigVector = BigVector::new(&[fraction(1, 5), fraction(3, 10), BigFraction::from(5)]);
+    assert!(cvp_enumerate(&basis, &target, Some(&BigFraction::from(25))).unwrap().is_empty());
+    let solutions: Vec<CVPSolution> = cvp_enumerate(&basis, &target, Some(&fraction(2601, 100))).unwrap();
+    assert_eq!(solutions.len(), 3);
+    assert_eq!(solutions[0].get_vector(), &int_vector(&[0, 0, 0]));
+    assert_eq!(solutions[0].get_distance_sq(), &fraction(2513, 100));
+}
+
+#[test]
+fn test_invalid_input() {
+    let basis: BigMatrix = int_matrix(&[&[1, 2], &[2, 4]]);
+    let target: BigVector = int_vector(&[1, 1]);
+    assert!(babai_round(&basis, &target).is_err());
+    assert!(babai_nearest_plane(&basis, &target).is_err());
+    assert!(cvp_enumerate(&basis, &target, None).is_err());
+    assert!(babai_nearest_plane(&BigMatrix::identity(3), &target).is_err());
+    assert!(cvp_enumerate(&BigMatrix::identity(2), &target, Some(&BigFraction::from(-1))).is_err());
+}
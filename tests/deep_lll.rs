@@ -0,0 +1,89 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::decomposition::ldl_decomposition::LDLDecomposition;
+use latticg::math::lattice::deep_lll::{deep_lll, deep_lll_fp};
+use latticg::math::lattice::lll::{LLLParams, LLLResult};
+use latticg::math::lattice::lll_fp::lll_fp;
+use common::{big_int_matrix, fraction, int_matrix, java_lcg_lattice};
+
+/// Checks the transformation and the deep insertion conditions `delta·|b*_i|^2 <= |pi_i(b_k)|^2` for every
+/// `i < depth` and `i >= k - depth`, which include the Lovász conditions
+fn check_deep_reduced(basis: &BigMatrix, result: &LLLResult, params: &LLLParams, depth: usize) {
+    let reduced: &BigMatrix = result.get_reduced_basis();
+    let u: &BigMatrix = result.get_transformations();
+    assert_eq!(u.determinant().unwrap().abs(), BigFraction::get_one());
+    let image: BigMatrix = u.multiply(basis).unwrap();
+    assert_eq!(image.submatrix(0, 0, result.get_rank(), basis.col_count()).unwrap(), *reduced);
+    for row in result.get_rank()..basis.row_count() {
+        assert!(image.get_row(row).unwrap().is_zero());
+    }
+    let ldl: LDLDecomposition = reduced.gram_matrix().ldl_decomposition().unwrap();
+    for k in 0..reduced.row_count() {
+        let mut projected: BigFraction = ldl.get_gso_norm_sq(k).unwrap();
+        for i in (0..k).rev() {
+            let mu: BigFraction = ldl.get_mu(k, i).unwrap();
+            assert!(mu.clone().abs() <= *params.get_eta());
+            let norm: BigFraction = ldl.get_gso_norm_sq(i).unwrap();
+            projected = projected + mu.clone() * mu * norm.clone();
+            if i < depth || i + depth >= k {
+                assert!(params.get_delta().clone() * norm <= projected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_exact_full_depth() {
+    let basis: BigMatrix = java_lcg_lattice(10);
+    let params: LLLParams = LLLParams::default();
+    let result: LLLResult = deep_lll(&basis, &params, 10).unwrap();
+    check_deep_reduced(&basis, &result, &params, 10);
+    assert_eq!(result.get_rank(), 10);
+}
+
+#[test]
+fn test_floating_point_depth() {
+    let basis: BigMatrix = java_lcg_lattice(30);
+    let params: LLLParams = LLLParams::default();
+    let result: LLLResult = deep_lll_fp(&basis, &params, 4).unwrap();
+    check_deep_reduced(&basis, &result, &params, 4);
+    let lll: LLLResult = lll_fp(&basis, &params).unwrap();
+    let first = |result: &LLLResult| result.get_reduced_basis().get_row(0).unwrap().magnitude_sq();
+    assert!(first(&result) <= first(&lll));
+}
+
+#[test]
+fn test_dependent_rows() {
+    let rows: Vec<Vec<BigInt>> = [[2, 4, 6], [1, 2, 3], [1, 0, 1], [3, 2, 5], [0, 0, 0]].iter()
+        .map(|row| row.iter().map(|&x| BigInt::from(x)).collect())
+        .collect();
+    let basis: BigMatrix = big_int_matrix(&rows);
+    let params: LLLParams = LLLParams::default();
+    for result in [deep_lll(&basis, &params, 2).unwrap(), deep_lll_fp(&basis, &params, 2).unwrap()].iter() {
+        check_deep_reduced(&basis, result, &params, 2);
+        assert_eq!(result.get_rank(), 2);
+        assert_eq!(result.get_num_dependent_vectors(), 3);
+    }
+}
+
+#[test]
+fn test_exact_ignores_eta() {
+    // eta 51/100 exceeds sqrt(26/100) but the exact reduction always size reduces to 1/2
+    let basis: BigMatrix = int_matrix(&[&[1, 1, 1], &[-1, 0, 2], &[3, 5, 6]]);
+    let params: LLLParams = LLLParams::new().with_delta(fraction(26, 100));
+    let result: LLLResult = deep_lll(&basis, &params, 2).unwrap();
+    check_deep_reduced(&basis, &result, &params, 2);
+    assert_eq!(result.get_rank(), 3);
+    assert!(deep_lll_fp(&basis, &params, 2).is_err());
+}
+
+#[test]
+fn test_invalid_depth() {
+    let basis: BigMatrix = BigMatrix::identity(2);
+    assert!(deep_lll(&basis, &LLLParams::default(), 0).is_err());
+    assert!(deep_lll_fp(&basis, &LLLParams::default(), 0).is_err());
+    assert!(deep_lll_fp(&basis, &LLLParams::default(), 1).is_ok());
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_float::BigFloat;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::lattice::diagnostics::Lattice;
+use latticg::math::lattice::lll::{lll, LLLParams};
+use latticg::math::lattice::lll_fp::lll_fp;
+use common::{fraction, int_matrix, java_lcg_lattice};
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() <= 1e-9 * b.abs().max(1.0), "{} != {}", a, b);
+}
+
+#[test]
+fn test_integer_lattice() {
+    let lattice: Lattice = Lattice::new(BigMatrix::identity(2)).unwrap();
+    assert_eq!(lattice.rank(), 2);
+    assert_eq!(lattice.determinant_sq(), BigFraction::get_one());
+    assert_eq!(lattice.determinant(64), BigFloat::from_int(&BigInt::from(1), 64));
+    assert_eq!(lattice.ln_determinant(), 0.0);
+    assert_close(lattice.orthogonality_defect(), 1.0);
+    assert_close(lattice.root_hermite_factor(), 1.0);
+    assert_close(lattice.gaussian_heuristic(), 1.0 / std::f64::consts::PI.sqrt());
+    assert_eq!(lattice.gso_log_profile(), vec![0.0, 0.0]);
+    assert!(lattice.is_size_reduced(&BigFraction::get_half()));
+    assert!(lattice.is_lll_reduced(&LLLParams::default()));
+    // pi·r^2 points in a disc of radius r
+    assert_close(lattice.expected_points(10.0), 100.0 * std::f64::consts::PI);
+}
+
+#[test]
+fn test_skewed_basis() {
+    let basis: BigMatrix = int_matrix(&[&[1, 0], &[99, 1]]);
+    let lattice: Lattice = Lattice::new(basis.clone()).unwrap();
+    assert_eq!(lattice.get_mu(1, 0).unwrap(), BigFraction::from(99));
+    assert!(lattice.get_mu(0, 1).is_err());
+    assert_close(Lattice::new(int_matrix(&[&[1, 1], &[-1, 1]])).unwrap().determinant(64).to_f64(), 2.0);
+    assert!(!lattice.is_size_reduced(&fraction(51, 100)));
+    assert!(!lattice.is_lll_reduced(&LLLParams::default()));
+    assert_close(lattice.orthogonality_defect(), (99.0f64 * 99.0 + 1.0).sqrt());
+    let reduced: Lattice = Lattice::new(lll(&basis, &LLLParams::default()).unwrap().get_reduced_basis().clone()).unwrap();
+    assert!(reduced.is_lll_reduced(&LLLParams::default()));
+    assert_eq!(reduced.determinant_sq(), lattice.determinant_sq());
+}
+
+#[test]
+fn test_lcg_lattice() {
+    let modulus: BigInt = BigInt::from(1) << 48usize;
+    let dimension: usize = 30;
+    let basis: BigMatrix = java_lcg_lattice(dimension);
+    let params: LLLParams = LLLParams::default();
+    let reduced: Lattice = Lattice::new(lll_fp(&basis, &params).unwrap().get_reduced_basis().clone()).unwrap();
+    let original: Lattice = Lattice::new(basis).unwrap();
+    assert!(reduced.is_lll_reduced(&params));
+    assert!(!original.is_lll_reduced(&params));
+    // the volume 2^(48·29) is far beyond f64 but the derived quantities are not
+    assert_eq!(reduced.determinant_sq(), BigFraction::from(modulus.pow(2 * (dimension as u32 - 1))));
+    assert_close(reduced.gso_log_profile().iter().sum(), 48.0 * 29.0);
+    assert_eq!(reduced.determinant(64), BigFloat::from_int(&modulus.pow(dimension as u32 - 1), 64));
+    assert_close(reduced.ln_determinant(), 48.0 * 29.0 * std::f64::consts::LN_2);
+    assert!(reduced.root_hermite_factor() < original.root_hermite_factor());
+    assert!(reduced.orthogonality_defect() < original.orthogonality_defect());
+    let heuristic: f64 = reduced.gaussian_heuristic();
+    assert_close(reduced.expected_points(heuristic), 1.0);
+    assert!(reduced.expected_enumeration_nodes(heuristic) < original.expected_enumeration_nodes(heuristic));
+}
+
+#[test]
+fn test_invalid_basis() {
+    assert!(Lattice::new(int_matrix(&[&[1, 2], &[2, 4]])).is_err());
+    assert!(Lattice::new(BigMatrix::zeros(0, 2)).is_err());
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use common::{fraction, int_matrix};
+
+#[test]
+fn test_rank() {
+    assert_eq!(BigMatrix::identity(4).rank(), 4);
+    assert_eq!(BigMatrix::zeros(3, 2).rank(), 0);
+    assert_eq!(int_matrix(&[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]]).rank(), 2);
+    assert_eq!(int_matrix(&[&[1, 2, 3, 4], &[2, 4, 6, 8]]).rank(), 1);
+    assert_eq!(int_matrix(&[&[0, 1], &[1, 0], &[1, 1]]).rank(), 2);
+}
+
+#[test]
+fn test_row_echelon() {
+    let a: BigMatrix = int_matrix(&[&[0, 2, 4], &[1, 1, 1], &[2, 4, 6]]);
+    let echelon: BigMatrix = a.row_echelon();
+    assert_eq!(echelon, int_matrix(&[&[1, 1, 1], &[0, 2, 4], &[0, 0, 0]]));
+}
+
+#[test]
+fn test_reduced_row_echelon() {
+    let a: BigMatrix = int_matrix(&[&[1, 2, 1, 4], &[2, 4, 0, 6], &[3, 6, 1, 10]]);
+    assert_eq!(a.reduced_row_echelon(), int_matrix(&[&[1, 2, 0, 3], &[0, 0, 1, 1], &[0, 0, 0, 0]]));
+    let b: BigMatrix = int_matrix(&[&[2, 1], &[1, 3]]);
+    assert_eq!(b.reduced_row_echelon(), BigMatrix::identity(2));
+    let c: BigMatrix = int_matrix(&[&[3, 1]]);
+    assert_eq!(c.reduced_row_echelon(), BigMatrix::from_rows(&[BigVector::new(&[fraction(1, 1), fraction(1, 3)])]).unwrap());
+}
+
+#[test]
+fn test_kernel_basis() {
+    let a: BigMatrix = int_matrix(&[&[1, 2, 1, 4], &[2, 4, 0, 6], &[3, 6, 1, 10]]);
+    let kernel: BigMatrix = a.kernel_basis();
+    assert_eq!(kernel.row_count(), 2);
+    assert_eq!(kernel.col_count(), 4);
+    assert_eq!(kernel, int_matrix(&[&[-2, 1, 0, 0], &[-3, 0, -1, 1]]));
+    assert!(a.multiply(&kernel.transpose()).unwrap().rows().iter().all(|row| row.is_zero()));
+    let full: BigMatrix = BigMatrix::identity(3).kernel_basis();
+    assert_eq!(full.row_count(), 0);
+    assert_eq!(full.col_count(), 3);
+}
+
+#[test]
+fn test_integral_kernel_basis() {
+    let a: BigMatrix = int_matrix(&[&[2, 3, 0], &[0, 0, 0]]);
+    assert_eq!(a.kernel_basis(), BigMatrix::from_rows(&[
+        BigVector::new(&[fraction(-3, 2), fraction(1, 1), fraction(0, 1)]),
+        BigVector::new(&[fraction(0, 1), fraction(0, 1), fraction(1, 1)]),
+    ]).unwrap());
+    assert_eq!(a.integral_kernel_basis(), int_matrix(&[&[-3, 2, 0], &[0, 0, 1]]));
+    let b: BigMatrix = int_matrix(&[&[4, 6, 8]]);
+    let kernel: BigMatrix = b.integral_kernel_basis();
+    assert_eq!(kernel, int_matrix(&[&[-3, 2, 0], &[-2, 0, 1]]));
+}
+
+#[test]
+fn test_image_basis() {
+    let a: BigMatrix = int_matrix(&[&[1, 2, 1, 4], &[2, 4, 0, 6], &[3, 6, 1, 10]]);
+    let image: BigMatrix = a.image_basis();
+    assert_eq!(image, int_matrix(&[&[1, 2, 3], &[1, 0, 1]]));
+    assert_eq!(image.rank(), a.rank());
+    assert_eq!(BigMatrix::zeros(2, 2).image_basis().row_count(), 0);
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::decomposition::hermite_normal_form::{hermite_normal_form_mod_d, HermiteNormalForm, HnfConvention};
+use common::{int_matrix, is_unimodular};
+
+fn check_row_hnf(h: &BigMatrix, rank: usize) {
+    let mut last_pivot: Option<usize> = None;
+    for row in 0..h.row_count() {
+        let pivot: Option<usize> = (0..h.col_count()).find(|&col| !h.get(row, col).unwrap().get_numerator().is_zero());
+        if row >= rank {
+            assert!(pivot.is_none());
+            continue;
+        }
+        let pivot: usize = pivot.expect("Nonzero row");
+        if let Some(last) = last_pivot {
+            assert!(pivot > last);
+        }
+        let pivot_value: BigInt = h.get(row, pivot).unwrap().get_numerator();
+        assert!(pivot_value.is_positive());
+        for above in 0..row {
+            let value: BigInt = h.get(above, pivot).unwrap().get_numerator();
+            assert!(!value.is_negative() && value < pivot_value);
+        }
+        last_pivot = Some(pivot);
+    }
+}
+
+#[test]
+fn test_row_hnf() {
+    let a: BigMatrix = int_matrix(&[&[2, 3, 6, 2], &[5, 6, 1, 6], &[8, 3, 1, 1]]);
+    let hnf: HermiteNormalForm = a.hermite_normal_form(HnfConvention::Row).unwrap();
+    assert_eq!(hnf.get_u().multiply(&a).unwrap(), *hnf.get_h());
+    assert!(is_unimodular(hnf.get_u()));
+    assert_eq!(hnf.get_rank(), 3);
+    check_row_hnf(hnf.get_h(), 3);
+    assert_eq!(*hnf.get_h(), int_matrix(&[&[1, 0, 50, -11], &[0, 3, 28, -2], &[0, 0, 61, -13]]));
+}
+
+#[test]
+fn test_row_hnf_rank_deficient() {
+    let a: BigMatrix = int_matrix(&[&[2, 4, 4], &[-6, 6, 12], &[-4, 10, 16], &[4, 8, 8]]);
+    let hnf: HermiteNormalForm = a.hermite_normal_form(HnfConvention::Row).unwrap();
+    assert_eq!(hnf.get_u().multiply(&a).unwrap(), *hnf.get_h());
+    assert!(is_unimodular(hnf.get_u()));
+    assert_eq!(hnf.get_rank(), 2);
+    check_row_hnf(hnf.get_h(), 2);
+    assert_eq!(hnf.get_basis(), int_matrix(&[&[2, 4, 4], &[0, 18, 24]]));
+}
+
+#[test]
+fn test_empty_keeps_shape() {
+    let rows: HermiteNormalForm = BigMatrix::zeros(0, 3).hermite_normal_form(HnfConvention::Row).unwrap();
+    assert_eq!(*rows.get_h(), BigMatrix::zeros(0, 3));
+    assert_eq!(rows.get_basis(), BigMatrix::zeros(0, 3));
+    assert_eq!(*rows.get_u(), BigMatrix::zeros(0, 0));
+    let cols: HermiteNormalForm = BigMatrix::zeros(3, 0).hermite_normal_form(HnfConvention::Column).unwrap();
+    assert_eq!(*cols.get_h(), BigMatrix::zeros(3, 0));
+}
+
+#[test]
+fn test_column_hnf() {
+    let a: BigMatrix = int_matrix(&[&[2, 3, 6, 2], &[5, 6, 1, 6], &[8, 3, 1, 1]]);
+    let hnf: HermiteNormalForm = a.hermite_normal_form(HnfConvention::Column).unwrap();
+    assert_eq!(a.multiply(hnf.get_u()).unwrap(), *hnf.get_h());
+    assert!(is_unimodular(hnf.get_u()));
+    check_row_hnf(&hnf.get_h().transpose(), 3);
+    assert_eq!(hnf.get_basis().row_count(), 3);
+}
+
+#[test]
+fn test_same_lattice() {
+    let a: BigMatrix = int_matrix(&[&[1, 2], &[3, 4]]);
+    let b: BigMatrix = int_matrix(&[&[3, 4], &[4, 6]]);
+    let c: BigMatrix = int_matrix(&[&[1, 0], &[0, 3]]);
+    let h_a: BigMatrix = a.hermite_normal_form(HnfConvention::Row).unwrap().get_basis();
+    let h_b: BigMatrix = b.hermite_normal_form(HnfConvention::Row).unwrap().get_basis();
+    let h_c: BigMatrix = c.hermite_normal_form(HnfConvention::Row).unwrap().get_basis();
+    assert_eq!(h_a, h_b);
+    assert_ne!(h_a, h_c);
+}
+
+#[test]
+fn test_lcg_congruence() {
+    // the pairs (x, y) with y ≡ a·x (mod 2^16) have the canonical basis (1, a mod 2^16), (0, 2^16)
+    let a: BigMatrix = int_matrix(&[&[5, 5 * 0x5DEECE66D], &[0, 65536], &[1, 0x5DEECE66D]]);
+    let hnf: HermiteNormalForm = a.hermite_normal_form(HnfConvention::Row).unwrap();
+    assert_eq!(hnf.get_rank(), 2);
+    assert_eq!(hnf.get_basis(), int_matrix(&[&[1, 0x5DEECE66D % 65536], &[0, 65536]]));
+}
+
+#[test]
+fn test_modular_matches_classical() {
+    let big: i64 = 1 << 62;
+    let a: BigMatrix = BigMatrix::from_fn(3, 3, |i, j| {
+        let entries: [[i64; 3]; 3] = [[big - 1, 7, 3], [11, big - 3, 5], [2, 13, big - 5]];
+        BigFraction::from(BigInt::from(entries[i][j]) * BigInt::from(big))
+    }).transpose();
+    let a: BigMatrix = BigMatrix::from_fn(3, 3, |i, j| {
+        if i == 0 && j == 0 { a.get(i, j).unwrap().add_int(BigInt::from(1)) } else { a.get(i, j).unwrap() }
+    });
+    let hnf: HermiteNormalForm = a.hermite_normal_form(HnfConvention::Row).unwrap();
+    assert_eq!(hnf.get_u().multiply(&a).unwrap(), *hnf.get_h());
+    assert!(is_unimodular(hnf.get_u()));
+    check_row_hnf(hnf.get_h(), 3);
+    let det: BigInt = a.determinant().unwrap().get_numerator();
+    let modular: BigMatrix = hermite_normal_form_mod_d(&a, &det).unwrap();
+    assert_eq!(modular, *hnf.get_h());
+}
+
+#[test]
+fn test_mod_d_q_ary() {
+    let q: i64 = 97;
+    let a: BigMatrix = int_matrix(&[&[1, 5, 25], &[3, 9, 27], &[97, 0, 0], &[0, 97, 0], &[0, 0, 97]]);
+    let classical: BigMatrix = a.hermite_normal_form(HnfConvention::Row).unwrap().get_basis();
+    let modular: BigMatrix = hermite_normal_form_mod_d(&a, &BigInt::from(q * q * q)).unwrap();
+    assert_eq!(modular, classical);
+    let tighter: BigMatrix = hermite_normal_form_mod_d(&a, &BigInt::from(q)).unwrap();
+    assert_eq!(tighter, classical);
+}
+
+#[test]
+fn test_non_integral() {
+    let a: BigMatrix = BigMatrix::from_rows(&[BigVector::new(&[BigFraction::get_half()])]).unwrap();
+    assert!(a.hermite_normal_form(HnfConvention::Row).is_err());
+    assert!(hermite_normal_form_mod_d(&a, &BigInt::from(2)).is_err());
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::decomposition::ldl_decomposition::LDLDecomposition;
+use common::{fraction, int_matrix};
+
+fn diagonal(values: &BigVector) -> BigMatrix {
+    let size: usize = values.dimension();
+    BigMatrix::from_fn(size, size, |row, col| if row == col { values.get(row).unwrap() } else { fraction(0, 1) })
+}
+
+#[test]
+fn test_gram_matrix() {
+    let basis: BigMatrix = int_matrix(&[&[1, 2, 0], &[0, 1, -1]]);
+    assert_eq!(basis.gram_matrix(), int_matrix(&[&[5, 2], &[2, 2]]));
+    assert_eq!(basis.gram_matrix(), basis.multiply(&basis.transpose()).unwrap());
+    assert_eq!(BigMatrix::zeros(0, 3).gram_matrix(), BigMatrix::zeros(0, 0));
+}
+
+#[test]
+fn test_gram_schmidt_data() {
+    // b0 = (1, 1, 1), b1 = (-1, 0, 2), b2 = (3, 5, 6)
+    let basis: BigMatrix = int_matrix(&[&[1, 1, 1], &[-1, 0, 2], &[3, 5, 6]]);
+    let ldl: LDLDecomposition = basis.gram_matrix().ldl_decomposition().unwrap();
+    assert_eq!(ldl.get_mu(1, 0).unwrap(), fraction(1, 3));
+    assert_eq!(ldl.get_mu(2, 0).unwrap(), fraction(14, 3));
+    assert_eq!(ldl.get_mu(2, 1).unwrap(), fraction(13, 14));
+    assert_eq!(ldl.get_d(), &BigVector::new(&[fraction(3, 1), fraction(14, 3), fraction(9, 14)]));
+    assert_eq!(ldl.get_gso_norm_sq(2).unwrap(), fraction(9, 14));
+    assert_eq!(ldl.det(), fraction(9, 1));
+    assert!(ldl.get_mu(0, 0).is_err());
+    assert!(ldl.get_mu(1, 2).is_err());
+}
+
+#[test]
+fn test_factorization() {
+    let a: BigMatrix = BigMatrix::from_rows(&[
+        BigVector::new(&[fraction(4, 1), fraction(1, 2), fraction(-1, 1)]),
+        BigVector::new(&[fraction(1, 2), fraction(3, 1), fraction(2, 3)]),
+        BigVector::new(&[fraction(-1, 1), fraction(2, 3), fraction(5, 1)]),
+    ]).unwrap();
+    let ldl: LDLDecomposition = LDLDecomposition::decompose(&a).unwrap();
+    let product: BigMatrix = ldl.get_l().multiply(&diagonal(ldl.get_d())).unwrap().multiply(&ldl.get_l().transpose()).unwrap();
+    assert_eq!(product, a);
+    assert_eq!(ldl.det(), a.determinant().unwrap());
+    for row in 0..3 {
+        assert_eq!(ldl.get_l().get(row, row).unwrap(), fraction(1, 1));
+        for col in row + 1..3 {
+            assert_eq!(ldl.get_l().get(row, col).unwrap(), fraction(0, 1));
+        }
+    }
+}
+
+#[test]
+fn test_errors() {
+    assert!(int_matrix(&[&[1, 2, 3]]).ldl_decomposition().is_err());
+    assert!(int_matrix(&[&[2, 1], &[0, 2]]).ldl_decomposition().is_err());
+    // indefinite and semidefinite matrices
+    assert!(int_matrix(&[&[1, 2], &[2, 1]]).ldl_decomposition().is_err());
+    assert!(int_matrix(&[&[1, 1, 0], &[1, 1, 0], &[0, 0, 1]]).ldl_decomposition().is_err());
+    assert!(int_matrix(&[&[-1]]).ldl_decomposition().is_err());
+    // the Gram matrix of dependent vectors is only semidefinite
+    assert!(int_matrix(&[&[1, 2], &[2, 4]]).gram_matrix().ldl_decomposition().is_err());
+}
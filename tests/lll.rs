@@ -0,0 +1,100 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::decomposition::ldl_decomposition::LDLDecomposition;
+use latticg::math::lattice::lll::{lll, LLLParams, LLLResult};
+use latticg::math::lattice::lll_fp::lll_fp;
+use common::{fraction, int_matrix, java_lcg_lattice};
+
+fn check_reduced(basis: &BigMatrix, result: &LLLResult, delta: &BigFraction) {
+    let reduced: &BigMatrix = result.get_reduced_basis();
+    let u: &BigMatrix = result.get_transformations();
+    assert_eq!(u.row_count(), basis.row_count());
+    assert!(u.is_integral());
+    assert_eq!(u.determinant().unwrap().abs(), fraction(1, 1));
+    let image: BigMatrix = u.multiply(basis).unwrap();
+    assert_eq!(image.submatrix(0, 0, result.get_rank(), basis.col_count()).unwrap(), *reduced);
+    for row in result.get_rank()..basis.row_count() {
+        assert!(image.get_row(row).unwrap().is_zero());
+    }
+    let ldl: LDLDecomposition = reduced.gram_matrix().ldl_decomposition().unwrap();
+    for i in 0..reduced.row_count() {
+        for j in 0..i {
+            assert!(ldl.get_mu(i, j).unwrap().abs() <= fraction(1, 2));
+        }
+        if i > 0 {
+            let mu: BigFraction = ldl.get_mu(i, i - 1).unwrap();
+            let previous: BigFraction = ldl.get_gso_norm_sq(i - 1).unwrap();
+            assert!(delta.clone() * previous.clone() <= ldl.get_gso_norm_sq(i).unwrap() + mu.clone() * mu * previous);
+        }
+    }
+}
+
+#[test]
+fn test_small_basis() {
+    let basis: BigMatrix = int_matrix(&[&[1, 1, 1], &[-1, 0, 2], &[3, 5, 6]]);
+    let params: LLLParams = LLLParams::new().with_delta(fraction(3, 4));
+    let result: LLLResult = lll(&basis, &params).unwrap();
+    check_reduced(&basis, &result, &fraction(3, 4));
+    assert_eq!(result.get_reduced_basis(), &int_matrix(&[&[0, 1, 0], &[1, 0, 1], &[-1, 0, 2]]));
+    assert_eq!(result.get_rank(), 3);
+    assert_eq!(result.get_num_dependent_vectors(), 0);
+}
+
+#[test]
+fn test_lcg_lattice() {
+    // lattice of consecutive outputs of x -> 25214903917 x mod 2^48
+    let basis: BigMatrix = java_lcg_lattice(6);
+    let params: LLLParams = LLLParams::default();
+    let result: LLLResult = lll(&basis, &params).unwrap();
+    check_reduced(&basis, &result, params.get_delta());
+    assert_eq!(result.get_reduced_basis().determinant().unwrap().abs(), basis.determinant().unwrap().abs());
+    // every vector is within a small factor of the Gaussian heuristic 2^40
+    for row in result.get_reduced_basis().rows() {
+        assert!(row.magnitude_sq() < BigFraction::from(BigInt::from(1) << 88));
+    }
+}
+
+#[test]
+fn test_dependent_rows() {
+    let basis: BigMatrix = int_matrix(&[&[2, 4, 6], &[1, 2, 3], &[1, 0, 1], &[3, 2, 5], &[0, 0, 0]]);
+    let result: LLLResult = lll(&basis, &LLLParams::default()).unwrap();
+    check_reduced(&basis, &result, &fraction(99, 100));
+    assert_eq!(result.get_rank(), 2);
+    assert_eq!(result.get_num_dependent_vectors(), 3);
+    assert_eq!(result.get_reduced_basis().row_count(), 2);
+}
+
+#[test]
+fn test_rational_basis() {
+    let basis: BigMatrix = BigMatrix::from_rows(&[
+        BigVector::new(&[fraction(1, 3), fraction(7, 2)]),
+        BigVector::new(&[fraction(1, 2), fraction(5, 1)]),
+    ]).unwrap();
+    let result: LLLResult = lll(&basis, &LLLParams::default()).unwrap();
+    check_reduced(&basis, &result, &fraction(99, 100));
+    assert!(!result.get_reduced_basis().is_integral());
+}
+
+#[test]
+fn test_invalid_delta() {
+    let basis: BigMatrix = BigMatrix::identity(2);
+    assert!(lll(&basis, &LLLParams::new().with_delta(fraction(1, 4))).is_err());
+    assert!(lll(&basis, &LLLParams::new().with_delta(fraction(101, 100))).is_err());
+    assert!(lll(&basis, &LLLParams::new().with_delta(fraction(1, 1))).is_ok());
+    let empty: LLLResult = lll(&BigMatrix::zeros(0, 3), &LLLParams::default()).unwrap();
+    assert_eq!(empty.get_rank(), 0);
+}
+
+#[test]
+fn test_exact_ignores_eta() {
+    // eta 51/100 exceeds sqrt(26/100) but the exact reduction always size reduces to 1/2
+    let basis: BigMatrix = int_matrix(&[&[1, 1, 1], &[-1, 0, 2], &[3, 5, 6]]);
+    let params: LLLParams = LLLParams::new().with_delta(fraction(26, 100));
+    let result: LLLResult = lll(&basis, &params).unwrap();
+    check_reduced(&basis, &result, &fraction(26, 100));
+    assert!(lll_fp(&basis, &params).is_err());
+}
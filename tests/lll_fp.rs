@@ -0,0 +1,81 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::decomposition::ldl_decomposition::LDLDecomposition;
+use latticg::math::lattice::lll::{lll, LLLParams, LLLResult};
+use latticg::math::lattice::lll_fp::lll_fp;
+use common::{big_int_matrix, lcg_lattice, java_lcg_lattice};
+
+fn check_reduced(basis: &BigMatrix, result: &LLLResult, params: &LLLParams) {
+    let reduced: &BigMatrix = result.get_reduced_basis();
+    let u: &BigMatrix = result.get_transformations();
+    assert_eq!(u.determinant().unwrap().abs(), BigFraction::get_one());
+    let image: BigMatrix = u.multiply(basis).unwrap();
+    assert_eq!(image.submatrix(0, 0, result.get_rank(), basis.col_count()).unwrap(), *reduced);
+    for row in result.get_rank()..basis.row_count() {
+        assert!(image.get_row(row).unwrap().is_zero());
+    }
+    let ldl: LDLDecomposition = reduced.gram_matrix().ldl_decomposition().unwrap();
+    for i in 0..reduced.row_count() {
+        for j in 0..i {
+            assert!(ldl.get_mu(i, j).unwrap().abs() <= *params.get_eta());
+        }
+        if i > 0 {
+            let mu: BigFraction = ldl.get_mu(i, i - 1).unwrap();
+            let previous: BigFraction = ldl.get_gso_norm_sq(i - 1).unwrap();
+            assert!(params.get_delta().clone() * previous.clone() <= ldl.get_gso_norm_sq(i).unwrap() + mu.clone() * mu * previous);
+        }
+    }
+}
+
+#[test]
+fn test_matches_exact_reduction() {
+    let basis: BigMatrix = java_lcg_lattice(8);
+    let params: LLLParams = LLLParams::default();
+    let result: LLLResult = lll_fp(&basis, &params).unwrap();
+    check_reduced(&basis, &result, &params);
+    let exact: LLLResult = lll(&basis, &params).unwrap();
+    assert_eq!(result.get_reduced_basis().determinant().unwrap().abs(), exact.get_reduced_basis().determinant().unwrap().abs());
+}
+
+#[test]
+fn test_medium_dimension() {
+    let basis: BigMatrix = java_lcg_lattice(30);
+    let params: LLLParams = LLLParams::default();
+    let result: LLLResult = lll_fp(&basis, &params).unwrap();
+    check_reduced(&basis, &result, &params);
+}
+
+#[test]
+fn test_entries_beyond_double_range() {
+    // Gram entries near 2^2400 overflow an f64, so the reduction has to escalate to multiprecision floats
+    let modulus: BigInt = BigInt::from(1) << 1200usize;
+    let multiplier: BigInt = (BigInt::from(1) << 1100usize) + BigInt::from(12345);
+    let basis: BigMatrix = lcg_lattice(&multiplier, &modulus, 5);
+    let params: LLLParams = LLLParams::default();
+    let result: LLLResult = lll_fp(&basis, &params).unwrap();
+    check_reduced(&basis, &result, &params);
+}
+
+#[test]
+fn test_dependent_rows() {
+    let rows: Vec<Vec<BigInt>> = [[2, 4, 6], [1, 2, 3], [1, 0, 1], [3, 2, 5]].iter()
+        .map(|row| row.iter().map(|&x| BigInt::from(x)).collect())
+        .collect();
+    let basis: BigMatrix = big_int_matrix(&rows);
+    let params: LLLParams = LLLParams::default();
+    let result: LLLResult = lll_fp(&basis, &params).unwrap();
+    check_reduced(&basis, &result, &params);
+    assert_eq!(result.get_rank(), 2);
+}
+
+#[test]
+fn test_invalid_eta() {
+    let half: BigFraction = BigFraction::get_half();
+    let basis: BigMatrix = BigMatrix::identity(2);
+    assert!(lll_fp(&basis, &LLLParams::new().with_eta(BigFraction::new(BigInt::from(1), BigInt::from(3)).unwrap())).is_err());
+    assert!(lll_fp(&basis, &LLLParams::new().with_eta(BigFraction::get_one())).is_err());
+    assert!(lll_fp(&basis, &LLLParams::new().with_eta(half)).is_ok());
+}
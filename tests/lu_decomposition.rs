@@ -0,0 +1,66 @@
+mod common;
+
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::decomposition::lu_decomposition::LUDecomposition;
+use common::{fraction, int_matrix};
+
+fn check_factorization(a: &BigMatrix, lu: &LUDecomposition) {
+    let size: usize = a.row_count();
+    assert_eq!(lu.get_p().multiply(a).unwrap(), lu.get_l().multiply(lu.get_u()).unwrap());
+    for row in 0..size {
+        assert_eq!(lu.get_l().get(row, row).unwrap(), fraction(1, 1));
+        for col in row + 1..size {
+            assert_eq!(lu.get_l().get(row, col).unwrap(), fraction(0, 1));
+            assert_eq!(lu.get_u().get(col, row).unwrap(), fraction(0, 1));
+        }
+    }
+}
+
+#[test]
+fn test_decompose_without_pivoting() {
+    let a: BigMatrix = int_matrix(&[&[2, 1, 1], &[4, -6, 0], &[-2, 7, 2]]);
+    let lu: LUDecomposition = LUDecomposition::decompose(&a).unwrap();
+    check_factorization(&a, &lu);
+    assert_eq!(lu.get_permutation(), &[0, 1, 2]);
+    assert_eq!(lu.get_sign(), 1);
+    assert_eq!(lu.det(), fraction(-16, 1));
+    assert!(!lu.is_singular());
+}
+
+#[test]
+fn test_decompose_with_pivoting() {
+    let a: BigMatrix = int_matrix(&[&[0, 1, 2], &[0, 0, 3], &[4, 5, 6]]);
+    let lu: LUDecomposition = LUDecomposition::decompose(&a).unwrap();
+    check_factorization(&a, &lu);
+    assert_eq!(lu.get_permutation(), &[2, 0, 1]);
+    assert_eq!(lu.get_swaps(), 2);
+    assert_eq!(lu.get_sign(), 1);
+    assert_eq!(lu.det(), fraction(12, 1));
+}
+
+#[test]
+fn test_decompose_rational() {
+    let a: BigMatrix = BigMatrix::from_fn(3, 3, |i, j| fraction(1, (i + j + 1) as i64));
+    let lu: LUDecomposition = a.lu_decomposition().unwrap();
+    check_factorization(&a, &lu);
+    assert_eq!(lu.det(), fraction(1, 2160));
+}
+
+#[test]
+fn test_decompose_singular() {
+    let a: BigMatrix = int_matrix(&[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]]);
+    let lu: LUDecomposition = LUDecomposition::decompose(&a).unwrap();
+    check_factorization(&a, &lu);
+    assert!(lu.is_singular());
+    assert_eq!(lu.det(), fraction(0, 1));
+}
+
+#[test]
+fn test_determinant() {
+    assert_eq!(int_matrix(&[&[0, 1], &[1, 0]]).determinant().unwrap(), fraction(-1, 1));
+    assert_eq!(BigMatrix::identity(5).determinant().unwrap(), fraction(1, 1));
+    assert_eq!(BigMatrix::zeros(0, 0).determinant().unwrap(), fraction(1, 1));
+    assert!(BigMatrix::zeros(2, 3).determinant().is_err());
+    let lcg: BigMatrix = int_matrix(&[&[1, 25214903917], &[0, 281474976710656]]);
+    assert_eq!(lcg.determinant().unwrap(), fraction(281474976710656, 1));
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::decomposition::hermite_normal_form::HnfConvention;
+use latticg::math::lattice::lll::{LLLParams, LLLResult};
+use latticg::math::lattice::mlll::mlll;
+use common::{int_matrix, java_lcg_lattice};
+
+fn check_generated(generators: &BigMatrix, result: &LLLResult) {
+    let u: &BigMatrix = result.get_transformations();
+    assert_eq!(u.determinant().unwrap().abs(), BigFraction::get_one());
+    let image: BigMatrix = u.multiply(generators).unwrap();
+    assert_eq!(&image.submatrix(0, 0, result.get_rank(), generators.col_count()).unwrap(), result.get_reduced_basis());
+    assert!(result.get_relations().multiply(generators).unwrap().rows().iter().all(|row| row.is_zero()));
+    // same lattice as the generators
+    let hnf = |matrix: &BigMatrix| matrix.hermite_normal_form(HnfConvention::Row).unwrap().get_basis();
+    assert_eq!(hnf(result.get_reduced_basis()), hnf(generators));
+}
+
+#[test]
+fn test_lcg_generating_set() {
+    // the powers of the multiplier together with m·e_i for every coordinate, one more generator than the rank
+    let modulus: BigInt = BigInt::from(1) << 48usize;
+    let dimension: usize = 8;
+    // the truncated lattice already holds m·e_i for i >= 1
+    let mut rows: Vec<BigVector> = java_lcg_lattice(dimension).rows();
+    rows.push(BigVector::basis(dimension, 0).unwrap().map(|x| x.clone() * BigFraction::from(modulus.clone())));
+    let generators: BigMatrix = BigMatrix::from_rows(&rows).unwrap();
+    let result: LLLResult = mlll(&generators, &LLLParams::default()).unwrap();
+    check_generated(&generators, &result);
+    assert_eq!(result.get_rank(), dimension);
+    assert_eq!(result.get_num_dependent_vectors(), 1);
+    let determinant: BigFraction = result.get_reduced_basis().determinant().unwrap().abs();
+    assert_eq!(determinant, BigFraction::from(modulus.pow(dimension as u32 - 1)));
+}
+
+#[test]
+fn test_reduced_relations() {
+    let generators: BigMatrix = int_matrix(&[&[4, 6, 2], &[6, 9, 3], &[2, 3, 1], &[1, 0, 5], &[3, 3, 6], &[10, 15, 5]]);
+    let result: LLLResult = mlll(&generators, &LLLParams::default()).unwrap();
+    check_generated(&generators, &result);
+    assert_eq!(result.get_rank(), 2);
+    let relations: BigMatrix = result.get_relations();
+    assert_eq!(relations.row_count(), 4);
+    // a short relation basis, every relation has small entries
+    for row in relations.rows() {
+        assert!(row.magnitude_sq() <= BigFraction::from(30));
+    }
+}
+
+#[test]
+fn test_zero_generators() {
+    let generators: BigMatrix = BigMatrix::zeros(3, 2);
+    let result: LLLResult = mlll(&generators, &LLLParams::default()).unwrap();
+    assert_eq!(result.get_rank(), 0);
+    assert_eq!(result.get_relations().row_count(), 3);
+    assert!(mlll(&generators, &LLLParams::new().with_delta(BigFraction::from(2))).is_err());
+}
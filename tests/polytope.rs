@@ -0,0 +1,89 @@
+mod common;
+
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::lattice::polytope::{enumerate_points, Constraints};
+use common::{int_vector, int_matrix};
+
+fn sorted(points: impl Iterator<Item=BigVector>) -> Vec<Vec<BigFraction>> {
+    let mut points: Vec<Vec<BigFraction>> = points.map(|point| point.to_vec()).collect();
+    points.sort();
+    points
+}
+
+#[test]
+fn test_box_matches_brute_force() {
+    let basis: BigMatrix = int_matrix(&[&[3, 1], &[1, 4]]);
+    let constraints: Constraints = Constraints::from_box(&int_vector(&[-10, -7]), &int_vector(&[9, 12])).unwrap();
+    let points: Vec<Vec<BigFraction>> = sorted(enumerate_points(&basis, &constraints).unwrap());
+    // (x, y) lies in the lattice of determinant 11 exactly when 4x - y = 0 mod 11
+    let mut expected: Vec<BigVector> = Vec::new();
+    for x in -10..=9i64 {
+        for y in -7..=12i64 {
+            if (4 * x - y).rem_euclid(11) == 0 {
+                expected.push(int_vector(&[x, y]));
+            }
+        }
+    }
+    assert_eq!(points, sorted(expected.into_iter()));
+    assert_eq!(points.len(), 36);
+}
+
+#[test]
+fn test_lcg_reversal() {
+    // states of x -> 25214903917 x mod 2^48 with only the top 16 bits of each known
+    let a: i64 = 25214903917;
+    let m: i64 = 1 << 48;
+    let seed: i64 = 0x5DEECE66D1A4;
+    let mut states: Vec<i64> = vec![seed];
+    for _ in 1..4 {
+        let last: i128 = *states.last().unwrap() as i128;
+        states.push((last * a as i128 % m as i128) as i64);
+    }
+    let mut rows: Vec<Vec<i64>> = vec![vec![1]];
+    for _ in 1..4 {
+        let last: i128 = *rows[0].last().unwrap() as i128;
+        rows[0].push((last * a as i128 % m as i128) as i64);
+    }
+    for i in 1..4 {
+        let mut row: Vec<i64> = vec![0; 4];
+        row[i] = m;
+        rows.push(row);
+    }
+    let basis: BigMatrix = int_matrix(&rows.iter().map(|row| row.as_slice()).collect::<Vec<&[i64]>>());
+    let lower: Vec<i64> = states.iter().map(|state| state >> 32 << 32).collect();
+    let upper: Vec<i64> = lower.iter().map(|low| low + (1 << 32) - 1).collect();
+    let constraints: Constraints = Constraints::from_box(&int_vector(&lower), &int_vector(&upper)).unwrap();
+    let points: Vec<BigVector> = enumerate_points(&basis, &constraints).unwrap().collect();
+    assert_eq!(points, vec![int_vector(&states)]);
+}
+
+#[test]
+fn test_extra_inequalities() {
+    let mut constraints: Constraints = Constraints::from_box(&int_vector(&[0, 0]), &int_vector(&[3, 3])).unwrap();
+    constraints.add_inequality(int_vector(&[1, 1]), BigFraction::from(3)).unwrap();
+    let points: Vec<Vec<BigFraction>> = sorted(enumerate_points(&BigMatrix::identity(2), &constraints).unwrap());
+    assert_eq!(points.len(), 10);
+    assert!(points.iter().all(|point| constraints.contains(&BigVector::new(point))));
+    assert!(constraints.add_inequality(int_vector(&[1, 1, 1]), BigFraction::from(3)).is_err());
+    // the iterator is lazy, a huge box still yields its first point
+    let huge: Constraints = Constraints::from_box(&int_vector(&[-1 << 40, -1 << 40]), &int_vector(&[1 << 40, 1 << 40])).unwrap();
+    assert_eq!(enumerate_points(&BigMatrix::identity(2), &huge).unwrap().take(3).count(), 3);
+}
+
+#[test]
+fn test_degenerate_regions() {
+    // dependent rows span a line, the box bounds it within the span
+    let line: BigMatrix = int_matrix(&[&[1, 1], &[2, 2]]);
+    let constraints: Constraints = Constraints::from_box(&int_vector(&[-3, -5]), &int_vector(&[3, 5])).unwrap();
+    assert_eq!(enumerate_points(&line, &constraints).unwrap().count(), 7);
+    let empty: Constraints = Constraints::from_box(&int_vector(&[1, 1]), &int_vector(&[2, 2])).unwrap();
+    assert_eq!(enumerate_points(&int_matrix(&[&[3, 0], &[0, 3]]), &empty).unwrap().count(), 0);
+    let zero: Vec<BigVector> = enumerate_points(&BigMatrix::zeros(0, 2), &constraints).unwrap().collect();
+    assert_eq!(zero, vec![int_vector(&[0, 0])]);
+    let mut half_plane: Constraints = Constraints::new(2);
+    half_plane.add_inequality(int_vector(&[1, 0]), BigFraction::from(0)).unwrap();
+    assert!(enumerate_points(&BigMatrix::identity(2), &half_plane).is_err());
+    assert!(enumerate_points(&BigMatrix::identity(3), &constraints).is_err());
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use std::convert::TryFrom;
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_vector::{BigVector, VectorFormat};
+use latticg::math::small_vector::SVector;
+use common::fraction;
+
+#[test]
+fn test_constructors() {
+    assert_eq!(SVector::<i64, 3>::zeros(), SVector::new([0, 0, 0]));
+    assert_eq!(SVector::<i64, 3>::basis(2).unwrap(), SVector::new([0, 0, 1]));
+    assert!(SVector::<i64, 3>::basis(3).is_err());
+    assert_eq!(SVector::<i64, 4>::from_fn(|i| i as i64 * 2), SVector::new([0, 2, 4, 6]));
+    assert_eq!(SVector::<i64, 4>::zeros().dimension(), 4);
+}
+
+#[test]
+fn test_get_set_swap() {
+    let mut a: SVector<i64, 3> = SVector::new([1, 2, 3]);
+    assert_eq!(a.get(1).unwrap(), 2);
+    assert!(a.get(3).is_err());
+    assert_eq!(a.set(0, 5).unwrap(), 5);
+    assert!(a.set(3, 5).is_err());
+    a.swap(0, 2).unwrap();
+    assert_eq!(a, SVector::new([3, 2, 5]));
+    assert!(a.swap(0, 3).is_err());
+}
+
+#[test]
+fn test_arithmetic_fractions() {
+    let mut a: SVector<BigFraction, 2> = SVector::new([fraction(1, 2), fraction(2, 3)]);
+    let b: SVector<BigFraction, 2> = SVector::new([fraction(1, 3), fraction(-1, 3)]);
+    a.add(&b);
+    assert_eq!(a, SVector::new([fraction(5, 6), fraction(1, 3)]));
+    a.sub(&b);
+    assert_eq!(a, SVector::new([fraction(1, 2), fraction(2, 3)]));
+    a.mul(&b);
+    assert_eq!(a, SVector::new([fraction(1, 6), fraction(-2, 9)]));
+    a.div(&b).unwrap();
+    assert_eq!(a, SVector::new([fraction(1, 2), fraction(2, 3)]));
+    assert!(a.div(&SVector::new([fraction(1, 1), fraction(0, 1)])).is_err());
+    a.scale(&fraction(6, 1));
+    assert_eq!(a, SVector::new([fraction(3, 1), fraction(4, 1)]));
+}
+
+#[test]
+fn test_dot_and_norms() {
+    let a: SVector<BigFraction, 3> = SVector::new([fraction(3, 1), fraction(-4, 1), fraction(1, 2)]);
+    let b: SVector<BigFraction, 3> = SVector::new([fraction(1, 1), fraction(1, 1), fraction(2, 1)]);
+    assert_eq!(a.dot(&b), fraction(0, 1));
+    assert_eq!(a.magnitude_sq(), fraction(101, 4));
+    assert_eq!(a.norm_l1(), fraction(15, 2));
+    assert_eq!(a.norm_linf(), fraction(4, 1));
+    assert!(!a.is_zero());
+    assert!(SVector::<BigFraction, 3>::zeros().is_zero());
+    let c: SVector<i64, 2> = SVector::new([-7, 2]);
+    assert_eq!(c.norm_linf(), 7);
+    assert_eq!(c.magnitude_sq(), 53);
+}
+
+#[test]
+fn test_map_and_zip_with() {
+    let a: SVector<i64, 3> = SVector::new([1, -2, 3]);
+    let b: SVector<i64, 3> = SVector::new([4, 5, 6]);
+    assert_eq!(a.map(|x| x * 2), SVector::new([2, -4, 6]));
+    assert_eq!(a.zip_with(&b, |x, y| x + y), SVector::new([5, 3, 9]));
+}
+
+#[test]
+fn test_conversions() {
+    let a: SVector<BigFraction, 3> = SVector::new([fraction(1, 2), fraction(0, 1), fraction(-4, 1)]);
+    let big: BigVector = BigVector::from(a.clone());
+    assert_eq!(big, BigVector::new(&[fraction(1, 2), fraction(0, 1), fraction(-4, 1)]));
+    assert_eq!(big.magnitude_sq(), a.magnitude_sq());
+    assert_eq!(SVector::<BigFraction, 3>::try_from(&big).unwrap(), a);
+    assert!(SVector::<BigFraction, 2>::try_from(&big).is_err());
+    let ints: BigVector = BigVector::from(SVector::new([1i64, -2]));
+    assert_eq!(ints, BigVector::new_int(&[BigInt::from(1), BigInt::from(-2)]));
+    assert_eq!(BigVector::from(SVector::new([BigInt::from(1), BigInt::from(-2)])), ints);
+}
+
+#[test]
+fn test_l2_weighted_and_quadratic_form() {
+    let a: SVector<BigFraction, 2> = SVector::new([fraction(3, 1), fraction(4, 1)]);
+    assert_eq!(a.norm_l2(), (BigInt::from(5), BigInt::from(5)));
+    let b: SVector<BigFraction, 2> = SVector::new([fraction(1, 1), fraction(1, 1)]);
+    assert_eq!(b.norm_l2(), (BigInt::from(1), BigInt::from(2)));
+    assert_eq!(a.weighted_norm_sq(&SVector::new([fraction(1, 1), fraction(1, 2)])), fraction(17, 1));
+    let gram: [SVector<BigFraction, 2>; 2] = [SVector::new([fraction(2, 1), fraction(1, 1)]), SVector::new([fraction(1, 1), fraction(3, 1)])];
+    assert_eq!(a.quadratic_form(&gram), fraction(90, 1));
+    let c: SVector<i64, 2> = SVector::new([1, 2]);
+    assert_eq!(c.quadratic_form(&[SVector::new([2, 1]), SVector::new([1, 3])]), 18);
+}
+
+#[test]
+fn test_modular() {
+    let modulus: BigInt = BigInt::from(7);
+    let a: SVector<BigFraction, 3> = SVector::new([fraction(-1, 1), fraction(10, 1), fraction(4, 1)]);
+    assert_eq!(a.reduce_mod(&modulus).unwrap(), SVector::new([fraction(6, 1), fraction(3, 1), fraction(4, 1)]));
+    assert_eq!(a.reduce_mod_centered(&modulus).unwrap(), SVector::new([fraction(-1, 1), fraction(3, 1), fraction(-3, 1)]));
+    assert!(a.is_congruent(&SVector::new([fraction(6, 1), fraction(3, 1), fraction(-3, 1)]), &modulus).unwrap());
+    assert!(!a.is_congruent(&SVector::<BigFraction, 3>::zeros(), &modulus).unwrap());
+    // 3·5 = 1 mod 7
+    assert_eq!(a.mod_inverse_scale(&BigInt::from(3), &modulus).unwrap(), SVector::new([fraction(2, 1), fraction(1, 1), fraction(6, 1)]));
+    assert!(a.mod_inverse_scale(&BigInt::from(14), &modulus).is_err());
+    assert!(a.reduce_mod(&BigInt::from(0)).is_err());
+    assert!(SVector::new([fraction(1, 2)]).reduce_mod(&modulus).is_err());
+    // agrees with BigVector
+    assert_eq!(BigVector::from(a.reduce_mod_centered(&modulus).unwrap()), BigVector::from(a).reduce_mod_centered(&modulus).unwrap());
+}
+
+#[test]
+fn test_display_and_parse() {
+    let a: SVector<BigFraction, 3> = SVector::new([fraction(1, 1), fraction(2, 3), fraction(-4, 1)]);
+    assert_eq!(a.to_string(), "{1, 2/3, -4}");
+    assert_eq!(a.format(VectorFormat::Fplll), "[1 2/3 -4]");
+    assert_eq!(a.format(VectorFormat::Sage), BigVector::from(a.clone()).format(VectorFormat::Sage));
+    assert_eq!("[1 2/3 -4]".parse::<SVector<BigFraction, 3>>().unwrap(), a);
+    assert_eq!(a.to_string().parse::<SVector<BigFraction, 3>>().unwrap(), a);
+    assert!("[1 2]".parse::<SVector<BigFraction, 3>>().is_err());
+    assert!("(1, 2, 3)".parse::<SVector<BigFraction, 3>>().is_err());
+}
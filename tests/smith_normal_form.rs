@@ -0,0 +1,106 @@
+mod common;
+
+use std::collections::HashSet;
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::decomposition::smith_normal_form::{QuotientGroup, SmithNormalForm};
+use common::{int_vector, int_matrix, is_unimodular};
+
+fn check_snf(a: &BigMatrix, snf: &SmithNormalForm) {
+    assert_eq!(snf.get_u().multiply(a).unwrap().multiply(snf.get_v()).unwrap(), *snf.get_d());
+    assert!(is_unimodular(snf.get_u()));
+    assert!(is_unimodular(snf.get_v()));
+    for row in 0..a.row_count() {
+        for col in 0..a.col_count() {
+            if row != col {
+                assert_eq!(snf.get_d().get(row, col).unwrap(), BigFraction::get_zero());
+            }
+        }
+    }
+    let diagonal: Vec<BigInt> = snf.get_diagonal();
+    for window in diagonal.windows(2) {
+        assert_eq!(&window[1] % &window[0], BigInt::from(0));
+    }
+}
+
+#[test]
+fn test_smith_normal_form() {
+    let a: BigMatrix = int_matrix(&[&[2, 4, 4], &[-6, 6, 12], &[10, -4, -16]]);
+    let snf: SmithNormalForm = a.smith_normal_form().unwrap();
+    check_snf(&a, &snf);
+    assert_eq!(snf.get_diagonal(), vec![BigInt::from(2), BigInt::from(6), BigInt::from(12)]);
+}
+
+#[test]
+fn test_smith_normal_form_rectangular() {
+    let a: BigMatrix = int_matrix(&[&[6, 4, 2], &[4, 2, 6]]);
+    let snf: SmithNormalForm = a.smith_normal_form().unwrap();
+    check_snf(&a, &snf);
+    assert_eq!(snf.get_diagonal(), vec![BigInt::from(2), BigInt::from(2)]);
+    let b: BigMatrix = a.transpose();
+    let snf: SmithNormalForm = b.smith_normal_form().unwrap();
+    check_snf(&b, &snf);
+    assert_eq!(snf.get_rank(), 2);
+}
+
+#[test]
+fn test_smith_normal_form_divisibility_fix() {
+    let a: BigMatrix = int_matrix(&[&[2, 0], &[0, 3]]);
+    let snf: SmithNormalForm = a.smith_normal_form().unwrap();
+    check_snf(&a, &snf);
+    assert_eq!(snf.get_diagonal(), vec![BigInt::from(1), BigInt::from(6)]);
+}
+
+#[test]
+fn test_smith_normal_form_singular() {
+    let a: BigMatrix = int_matrix(&[&[1, 2, 3], &[2, 4, 6], &[0, 0, 0]]);
+    let snf: SmithNormalForm = a.smith_normal_form().unwrap();
+    check_snf(&a, &snf);
+    assert_eq!(snf.get_rank(), 1);
+    assert!(BigMatrix::from_rows(&[BigVector::new(&[BigFraction::get_half()])]).unwrap().smith_normal_form().is_err());
+    let empty: SmithNormalForm = BigMatrix::zeros(0, 3).smith_normal_form().unwrap();
+    assert_eq!(*empty.get_d(), BigMatrix::zeros(0, 3));
+    assert_eq!(*empty.get_v(), BigMatrix::identity(3));
+}
+
+#[test]
+fn test_quotient_group() {
+    let group: QuotientGroup = QuotientGroup::new(&int_matrix(&[&[2, 0], &[0, 3]])).unwrap();
+    assert_eq!(group.invariant_factors(), &[BigInt::from(6)]);
+    assert_eq!(group.free_rank(), 0);
+    assert_eq!(group.order(), Some(BigInt::from(6)));
+    let infinite: QuotientGroup = QuotientGroup::new(&int_matrix(&[&[4, 2, 0]])).unwrap();
+    assert_eq!(infinite.invariant_factors(), &[BigInt::from(2)]);
+    assert_eq!(infinite.free_rank(), 2);
+    assert_eq!(infinite.order(), None);
+    assert!(infinite.coset_representatives().is_err());
+}
+
+#[test]
+fn test_coset_representatives() {
+    let lattice: BigMatrix = int_matrix(&[&[4, 1, 0], &[0, 3, 1], &[2, 0, 6]]);
+    let group: QuotientGroup = QuotientGroup::new(&lattice).unwrap();
+    let order: BigInt = group.order().unwrap();
+    assert_eq!(order, BigInt::from(74));
+    let representatives: Vec<BigVector> = group.coset_representatives().unwrap().collect();
+    assert_eq!(BigInt::from(representatives.len()), order);
+    let classes: HashSet<Vec<BigInt>> = representatives.iter().map(|x| group.coordinates(x).unwrap()).collect();
+    assert_eq!(BigInt::from(classes.len()), order);
+    for representative in representatives.iter() {
+        assert!(representative.is_integral());
+    }
+}
+
+#[test]
+fn test_coordinates_of_lattice_vectors() {
+    let lattice: BigMatrix = int_matrix(&[&[1, 0x5DEECE66D % 4096], &[0, 4096]]);
+    let group: QuotientGroup = QuotientGroup::new(&lattice).unwrap();
+    assert_eq!(group.order(), Some(BigInt::from(4096)));
+    let zero: Vec<BigInt> = group.coordinates(&int_vector(&[0, 0])).unwrap();
+    let member: BigVector = int_vector(&[3, 3 * (0x5DEECE66D % 4096) - 4096 * 5]);
+    assert_eq!(group.coordinates(&member).unwrap(), zero);
+    assert_ne!(group.coordinates(&int_vector(&[0, 1])).unwrap(), zero);
+    assert!(group.coordinates(&int_vector(&[1])).is_err());
+}
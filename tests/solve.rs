@@ -0,0 +1,73 @@
+mod common;
+
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::decomposition::lu_decomposition::SolveErrorKind;
+use common::{fraction, int_vector, int_matrix};
+
+#[test]
+fn test_solve() {
+    let a: BigMatrix = int_matrix(&[&[2, 1, 1], &[4, -6, 0], &[-2, 7, 2]]);
+    let b: BigVector = int_vector(&[5, -2, 9]);
+    let x: BigVector = a.solve(&b).unwrap();
+    assert_eq!(x, int_vector(&[1, 1, 2]));
+    assert_eq!(a.multiply_vector(&x).unwrap(), b);
+}
+
+#[test]
+fn test_solve_rational_with_pivoting() {
+    let a: BigMatrix = int_matrix(&[&[0, 3], &[2, 1]]);
+    let b: BigVector = int_vector(&[1, 1]);
+    let x: BigVector = a.solve(&b).unwrap();
+    assert_eq!(x, BigVector::new(&[fraction(1, 3), fraction(1, 3)]));
+}
+
+#[test]
+fn test_solve_errors() {
+    let singular: BigMatrix = int_matrix(&[&[1, 2], &[2, 4]]);
+    assert_eq!(singular.solve(&int_vector(&[1, 2])).unwrap_err().kind(), SolveErrorKind::Singular);
+    assert_eq!(singular.solve(&int_vector(&[1, 2])).unwrap_err().to_string(), "the matrix is singular");
+    assert_eq!(singular.solve(&int_vector(&[1])).unwrap_err().kind(), SolveErrorKind::DimensionMismatch);
+    assert_eq!(BigMatrix::zeros(2, 3).solve(&int_vector(&[1, 2])).unwrap_err().kind(), SolveErrorKind::NotSquare);
+    assert_eq!(singular.inverse().unwrap_err().kind(), SolveErrorKind::Singular);
+}
+
+#[test]
+fn test_solve_matrix() {
+    let a: BigMatrix = int_matrix(&[&[1, 2], &[3, 4]]);
+    let b: BigMatrix = int_matrix(&[&[5, 6, 1], &[7, 8, 0]]);
+    let x: BigMatrix = a.solve_matrix(&b).unwrap();
+    assert_eq!(a.multiply(&x).unwrap(), b);
+    assert_eq!(a.solve_matrix(&BigMatrix::zeros(3, 1)).unwrap_err().kind(), SolveErrorKind::DimensionMismatch);
+}
+
+#[test]
+fn test_inverse() {
+    let a: BigMatrix = int_matrix(&[&[4, 7], &[2, 6]]);
+    let inverse: BigMatrix = a.inverse().unwrap();
+    assert_eq!(inverse, BigMatrix::from_rows(&[
+        BigVector::new(&[fraction(3, 5), fraction(-7, 10)]),
+        BigVector::new(&[fraction(-1, 5), fraction(2, 5)]),
+    ]).unwrap());
+    assert_eq!(a.multiply(&inverse).unwrap(), BigMatrix::identity(2));
+    assert_eq!(inverse.multiply(&a).unwrap(), BigMatrix::identity(2));
+}
+
+#[test]
+fn test_inverse_lcg_basis() {
+    let a: BigMatrix = int_matrix(&[&[1, 25214903917, 0], &[0, 281474976710656, 11], &[0, 0, 1]]);
+    let inverse: BigMatrix = a.inverse().unwrap();
+    assert_eq!(a.multiply(&inverse).unwrap(), BigMatrix::identity(3));
+}
+
+#[test]
+fn test_least_squares() {
+    let a: BigMatrix = int_matrix(&[&[1, 0], &[1, 1], &[1, 2]]);
+    let b: BigVector = int_vector(&[6, 0, 0]);
+    assert_eq!(a.least_squares(&b).unwrap(), int_vector(&[5, -3]));
+    let consistent: BigVector = int_vector(&[1, 3, 5]);
+    assert_eq!(a.least_squares(&consistent).unwrap(), int_vector(&[1, 2]));
+    let dependent: BigMatrix = int_matrix(&[&[1, 2], &[2, 4], &[3, 6]]);
+    assert_eq!(dependent.least_squares(&b).unwrap_err().kind(), SolveErrorKind::RankDeficient);
+    assert_eq!(a.least_squares(&int_vector(&[1])).unwrap_err().kind(), SolveErrorKind::DimensionMismatch);
+}
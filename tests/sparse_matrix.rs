@@ -0,0 +1,87 @@
+mod common;
+
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::sparse_matrix::SparseMatrix;
+use common::{fraction, int_vector, int_matrix};
+
+#[test]
+fn test_dense_round_trip() {
+    let a: BigMatrix = int_matrix(&[&[1, 0, 0, 5], &[0, 0, 0, 0], &[0, -2, 0, 7]]);
+    let sparse: SparseMatrix = SparseMatrix::from(&a);
+    assert_eq!(sparse.nnz(), 4);
+    assert_eq!(sparse.row_count(), 3);
+    assert_eq!(sparse.col_count(), 4);
+    assert_eq!(sparse.row(2).unwrap().collect::<Vec<_>>(), vec![(1, &fraction(-2, 1)), (3, &fraction(7, 1))]);
+    assert!(sparse.row(3).is_err());
+    assert!(sparse.row(usize::MAX).is_err());
+    assert_eq!(BigMatrix::from(&sparse), a);
+    assert_eq!(SparseMatrix::identity(3).to_dense(), BigMatrix::identity(3));
+    assert_eq!(SparseMatrix::zeros(2, 3).to_dense(), BigMatrix::zeros(2, 3));
+}
+
+#[test]
+fn test_from_triplets() {
+    let triplets: Vec<(usize, usize, BigFraction)> = vec![
+        (1, 2, fraction(1, 2)),
+        (0, 1, fraction(3, 1)),
+        (1, 2, fraction(1, 2)),
+        (1, 0, fraction(4, 1)),
+        (0, 0, fraction(2, 1)),
+        (0, 0, fraction(-2, 1)),
+    ];
+    let sparse: SparseMatrix = SparseMatrix::from_triplets(2, 3, &triplets).unwrap();
+    assert_eq!(sparse.nnz(), 3);
+    assert_eq!(sparse.to_dense(), int_matrix(&[&[0, 3, 0], &[4, 0, 1]]));
+    assert!(SparseMatrix::from_triplets(2, 3, &[(2, 0, fraction(1, 1))]).is_err());
+}
+
+#[test]
+fn test_get_set() {
+    let mut sparse: SparseMatrix = SparseMatrix::zeros(2, 3);
+    sparse.set(1, 2, fraction(5, 1)).unwrap();
+    sparse.set(0, 1, fraction(1, 3)).unwrap();
+    sparse.set(1, 0, fraction(-1, 1)).unwrap();
+    assert_eq!(sparse.nnz(), 3);
+    assert_eq!(sparse.get(0, 1).unwrap(), fraction(1, 3));
+    assert_eq!(sparse.get(0, 0).unwrap(), fraction(0, 1));
+    sparse.set(0, 1, fraction(0, 1)).unwrap();
+    assert_eq!(sparse.nnz(), 2);
+    assert_eq!(sparse.to_dense(), int_matrix(&[&[0, 0, 0], &[-1, 0, 5]]));
+    assert!(sparse.get(2, 0).is_err());
+    assert!(sparse.set(0, 3, fraction(1, 1)).is_err());
+}
+
+#[test]
+fn test_transpose() {
+    let a: BigMatrix = int_matrix(&[&[1, 0, 2], &[0, 0, 3], &[4, 0, 0], &[0, 5, 0]]);
+    let sparse: SparseMatrix = SparseMatrix::from_dense(&a);
+    assert_eq!(sparse.transpose().to_dense(), a.transpose());
+    assert_eq!(sparse.transpose().transpose(), sparse);
+}
+
+#[test]
+fn test_multiply() {
+    let a: BigMatrix = int_matrix(&[&[1, 0, 2], &[0, 0, 3], &[-1, 1, 0]]);
+    let b: BigMatrix = int_matrix(&[&[0, 1], &[2, 0], &[1, 0]]);
+    let sa: SparseMatrix = SparseMatrix::from_dense(&a);
+    let sb: SparseMatrix = SparseMatrix::from_dense(&b);
+    assert_eq!(sa.multiply(&sb).unwrap().to_dense(), a.multiply(&b).unwrap());
+    assert_eq!(sa.multiply_dense(&b).unwrap(), a.multiply(&b).unwrap());
+    assert!(sb.multiply(&sb).is_err());
+    assert!(sb.multiply_dense(&b).is_err());
+    // products cancelling to zero are not stored
+    let c: SparseMatrix = SparseMatrix::from_dense(&int_matrix(&[&[1, 1]]));
+    let d: SparseMatrix = SparseMatrix::from_dense(&int_matrix(&[&[1], &[-1]]));
+    assert_eq!(c.multiply(&d).unwrap().nnz(), 0);
+}
+
+#[test]
+fn test_multiply_vector() {
+    let a: BigMatrix = int_matrix(&[&[1, 0, 2], &[0, 0, 3]]);
+    let v: BigVector = BigVector::new(&[fraction(1, 2), fraction(7, 1), fraction(-1, 1)]);
+    let sparse: SparseMatrix = SparseMatrix::from_dense(&a);
+    assert_eq!(sparse.multiply_vector(&v).unwrap(), a.multiply_vector(&v).unwrap());
+    assert!(sparse.multiply_vector(&int_vector(&[1, 2])).is_err());
+}
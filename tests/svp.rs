@@ -0,0 +1,63 @@
+mod common;
+
+use num_bigint::BigInt;
+use latticg::math::big_fraction::BigFraction;
+use latticg::math::big_matrix::BigMatrix;
+use latticg::math::big_vector::BigVector;
+use latticg::math::lattice::lll::{lll, LLLParams};
+use latticg::math::lattice::svp::{svp_enumerate, SVPParams, SVPResult};
+use common::{int_matrix, java_lcg_lattice, shortest_norm_sq};
+
+fn check_solution(basis: &BigMatrix, result: &SVPResult) {
+    let coefficients: &BigVector = result.get_coefficients().unwrap();
+    assert!(coefficients.is_integral());
+    let vector: &BigVector = result.get_vector().unwrap();
+    assert_eq!(&basis.transpose().multiply_vector(coefficients).unwrap(), vector);
+    assert_eq!(&vector.magnitude_sq(), result.get_norm_sq().unwrap());
+}
+
+#[test]
+fn test_unreduced_basis() {
+    // a skewed basis of Z^2
+    let basis: BigMatrix = int_matrix(&[&[5, 8], &[3, 5]]);
+    let result: SVPResult = svp_enumerate(&basis, None, &SVPParams::default()).unwrap();
+    assert!(result.is_complete());
+    check_solution(&basis, &result);
+    assert_eq!(result.get_norm_sq().unwrap(), &BigFraction::get_one());
+}
+
+#[test]
+fn test_matches_brute_force() {
+    let reduced: BigMatrix = lll(&java_lcg_lattice(5), &LLLParams::default()).unwrap().get_reduced_basis().clone();
+    let result: SVPResult = svp_enumerate(&reduced, None, &SVPParams::default()).unwrap();
+    assert!(result.is_complete());
+    check_solution(&reduced, &result);
+    assert_eq!(result.get_norm_sq().unwrap(), &shortest_norm_sq(&reduced, 2));
+    // without shrinking every vector of the ball is visited, which takes more nodes for the same answer
+    let fixed: SVPResult = svp_enumerate(&reduced, None, &SVPParams::new().with_shrink_radius(false)).unwrap();
+    assert_eq!(fixed.get_norm_sq(), result.get_norm_sq());
+    assert!(fixed.get_nodes() >= result.get_nodes());
+}
+
+#[test]
+fn test_radius_and_node_limit() {
+    let basis: BigMatrix = int_matrix(&[&[3, 1, 0], &[1, 3, 1], &[0, 1, 3]]);
+    let shortest: SVPResult = svp_enumerate(&basis, None, &SVPParams::default()).unwrap();
+    let norm_sq: BigFraction = shortest.get_norm_sq().unwrap().clone();
+    // nothing strictly inside the first minimum, a complete search proves it
+    let below: BigFraction = norm_sq.clone() - BigFraction::new(BigInt::from(1), BigInt::from(2)).unwrap();
+    let empty: SVPResult = svp_enumerate(&basis, Some(&below), &SVPParams::default()).unwrap();
+    assert!(empty.is_complete());
+    assert!(empty.get_vector().is_none());
+    let limited: SVPResult = svp_enumerate(&basis, Some(&norm_sq), &SVPParams::new().with_node_limit(Some(1))).unwrap();
+    assert!(!limited.is_complete());
+}
+
+#[test]
+fn test_invalid_input() {
+    let dependent: BigMatrix = int_matrix(&[&[1, 2], &[2, 4]]);
+    assert!(svp_enumerate(&dependent, None, &SVPParams::default()).is_err());
+    let basis: BigMatrix = BigMatrix::identity(2);
+    assert!(svp_enumerate(&basis, Some(&BigFraction::from(-1)), &SVPParams::default()).is_err());
+    assert!(svp_enumerate(&BigMatrix::zeros(0, 2), None, &SVPParams::default()).unwrap().get_vector().is_none());
+}